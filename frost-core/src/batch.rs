@@ -103,7 +103,7 @@ where
     /// notation in the [protocol specification §B.1][ps].
     ///
     /// [ps]: https://zips.z.cash/protocol/protocol.pdf#reddsabatchverify
-    pub fn verify<R: RngCore + CryptoRng>(self, mut rng: R) -> Result<(), Error<C>> {
+    pub fn verify<R: RngCore + CryptoRng>(self, rng: &mut R) -> Result<(), Error<C>> {
         let n = self.signatures.len();
 
         if n == 0 {
@@ -117,10 +117,12 @@ where
         let mut P_coeff_acc = <<C::Group as Group>::Field>::zero();
 
         for item in self.signatures.iter() {
+            item.sig.check_non_degenerate()?;
+
             let z = item.sig.z;
             let R = item.sig.R;
 
-            let blind = <<C::Group as Group>::Field>::random(&mut rng);
+            let blind = <<C::Group as Group>::Field>::random(rng);
 
             let P_coeff = blind * z;
             P_coeff_acc = P_coeff_acc - P_coeff;
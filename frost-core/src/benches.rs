@@ -86,7 +86,7 @@ pub fn bench_sign<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
     let mut group = c.benchmark_group(format!("FROST Signing {name}"));
     for &n in [3u16, 10, 100, 1000].iter() {
         let max_signers = n;
-        let min_signers = (n * 2 + 2) / 3;
+        let min_signers = (n * 2).div_ceil(3);
 
         group.bench_with_input(
             BenchmarkId::new("Key Generation with Dealer", max_signers),
@@ -195,3 +195,73 @@ pub fn bench_sign<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
     }
     group.finish();
 }
+
+/// Benchmark verifying the signature shares of a 32-signer session
+/// individually and in a batch, with the specified ciphersuite.
+pub fn bench_batch_verify_shares<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
+    c: &mut Criterion,
+    name: &str,
+    rng: &mut R,
+) {
+    let mut group = c.benchmark_group(format!("Signature Share Verification {name}"));
+    let n = 32u16;
+
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer::<C, R>(n, n, frost::keys::IdentifierList::Default, rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonce, commitment) = frost::round1::commit(key_package.signing_share(), rng);
+        nonces.insert(*identifier, nonce);
+        commitments.insert(*identifier, commitment);
+    }
+    let signing_package = frost::SigningPackage::new(commitments, b"message to sign");
+    let signature_shares: BTreeMap<_, _> = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            let nonce = nonces.get(identifier).unwrap();
+            let share = frost::round2::sign(&signing_package, nonce, key_package);
+            (*identifier, share.unwrap())
+        })
+        .collect();
+
+    group.bench_with_input(
+        BenchmarkId::new("Unbatched verification", n),
+        &signature_shares,
+        |b, signature_shares| {
+            b.iter(|| {
+                for (identifier, signature_share) in signature_shares {
+                    frost::round2::check_share(
+                        signature_share,
+                        *identifier,
+                        &signing_package,
+                        pubkeys.verifying_shares().get(identifier).unwrap(),
+                        pubkeys.verifying_key(),
+                    )
+                    .unwrap();
+                }
+            })
+        },
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new("Batched verification", n),
+        &signature_shares,
+        |b, signature_shares| {
+            let mut rng = rng.clone();
+            b.iter(|| {
+                frost::round2::batch_verify_shares(
+                    &signing_package,
+                    signature_shares,
+                    &pubkeys,
+                    &mut rng,
+                )
+                .unwrap();
+            })
+        },
+    );
+    group.finish();
+}
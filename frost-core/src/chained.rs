@@ -0,0 +1,121 @@
+//! Threshold signing of a stream as a chain of signed chunks.
+//!
+//! Each chunk is signed over `H4(R_prev || last || chunk)`, where `R_prev` is
+//! the commitment `R` of the previous chunk's signature (and is empty for the
+//! first chunk) and `last` is a byte set to 1 for the final chunk of the stream
+//! and to 0 otherwise, so that each signature commits to the whole stream so
+//! far. The chain can then be verified incrementally with [`verify_chain`],
+//! which rejects reordered, dropped, modified or truncated chunks.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    keys::PublicKeyPackage, round1::SigningCommitments, round2::SignatureShare, Ciphersuite,
+    Element, Error, Group, Identifier, Signature, SigningPackage, VerifyingKey,
+};
+
+/// Return the message signed for `chunk`, given the `R` of the previous
+/// chunk's signature and whether `chunk` is the last one of the stream.
+fn chained_message<C: Ciphersuite>(
+    previous_R: Option<&Element<C>>,
+    chunk: &[u8],
+    last: bool,
+) -> Vec<u8> {
+    let mut preimage = vec![];
+    if let Some(previous_R) = previous_R {
+        preimage.extend_from_slice(<C::Group>::serialize(previous_R).as_ref());
+    }
+    preimage.push(last as u8);
+    preimage.extend_from_slice(chunk);
+
+    C::H4(&preimage).as_ref().to_vec()
+}
+
+/// The coordinator's state while signing a stream chunk by chunk.
+///
+/// For each chunk, the coordinator creates the [`SigningPackage`] with
+/// [`ChainedSigningSession::signing_package`], runs round two with the
+/// signers as usual, and aggregates the shares with
+/// [`ChainedSigningSession::aggregate`], which advances the chain. The last
+/// chunk of the stream must be signed with `last` set, so that a truncated
+/// stream can be detected.
+#[derive(Clone, Debug)]
+pub struct ChainedSigningSession<C: Ciphersuite> {
+    previous_signature: Option<Signature<C>>,
+}
+
+impl<C> ChainedSigningSession<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`ChainedSigningSession`] for a new stream.
+    pub fn new() -> Self {
+        Self {
+            previous_signature: None,
+        }
+    }
+
+    /// Return the message to sign for the next `chunk` of the stream, which
+    /// is the `last` one of the stream if set.
+    pub fn message(&self, chunk: &[u8], last: bool) -> Vec<u8> {
+        chained_message::<C>(self.previous_signature.as_ref().map(|s| &s.R), chunk, last)
+    }
+
+    /// Create the [`SigningPackage`] for the next `chunk` of the stream, which
+    /// is the `last` one of the stream if set.
+    pub fn signing_package(
+        &self,
+        signing_commitments: BTreeMap<Identifier<C>, SigningCommitments<C>>,
+        chunk: &[u8],
+        last: bool,
+    ) -> SigningPackage<C> {
+        SigningPackage::new(signing_commitments, &self.message(chunk, last))
+    }
+
+    /// Aggregate the signature shares for the next chunk with
+    /// [`aggregate`](crate::aggregate), and advance the chain to it.
+    pub fn aggregate(
+        &mut self,
+        signing_package: &SigningPackage<C>,
+        signature_shares: &BTreeMap<Identifier<C>, SignatureShare<C>>,
+        pubkeys: &PublicKeyPackage<C>,
+    ) -> Result<Signature<C>, Error<C>> {
+        let signature = crate::aggregate(signing_package, signature_shares, pubkeys)?;
+        self.previous_signature = Some(signature);
+        Ok(signature)
+    }
+}
+
+impl<C> Default for ChainedSigningSession<C>
+where
+    C: Ciphersuite,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Verify the signatures of a stream signed with a [`ChainedSigningSession`],
+/// given its chunks in order.
+///
+/// Returns [`Error::InvalidSignature`] if the numbers of chunks and signatures
+/// differ or if any signature is invalid, e.g. because chunks were reordered or
+/// the stream was truncated.
+pub fn verify_chain<C: Ciphersuite>(
+    chunks: &[&[u8]],
+    signatures: &[Signature<C>],
+    verifying_key: &VerifyingKey<C>,
+) -> Result<(), Error<C>> {
+    if chunks.len() != signatures.len() {
+        return Err(Error::InvalidSignature);
+    }
+
+    let mut previous_R = None;
+    for (i, (chunk, signature)) in chunks.iter().zip(signatures).enumerate() {
+        let last = i + 1 == chunks.len();
+        verifying_key.verify(&chained_message::<C>(previous_R, chunk, last), signature)?;
+        previous_R = Some(&signature.R);
+    }
+
+    Ok(())
+}
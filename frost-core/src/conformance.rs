@@ -0,0 +1,115 @@
+//! Ciphersuite conformance harness.
+//!
+//! [`run`] exercises a full key generation, signing, aggregation and
+//! verification flow for a ciphersuite, and checks that the encodings of its
+//! scalars, elements and FROST types round-trip. Ciphersuite implementations
+//! should call it from their test suite, since it catches common mistakes such
+//! as using the wrong endianness or accepting the identity element.
+#![allow(clippy::unwrap_used)]
+
+use debugless_unwrap::DebuglessUnwrap;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    keys::{self, IdentifierList, KeyPackage},
+    tests::ciphersuite_generic::check_sign_with_dealer,
+    Ciphersuite, Field, Group, Identifier, Signature, VerifyingKey,
+};
+
+/// Run all conformance checks for the ciphersuite `C`, panicking if any of
+/// them fails.
+pub fn run<C: Ciphersuite, R: RngCore + CryptoRng>(rng: &mut R) {
+    check_field::<C, R>(rng);
+    check_group::<C, R>(rng);
+    check_sign::<C, R>(rng);
+    check_keys::<C, R>(rng);
+}
+
+/// Check that scalars round-trip and that the little-endian encoding is
+/// actually little-endian.
+fn check_field<C: Ciphersuite, R: RngCore + CryptoRng>(rng: &mut R) {
+    type F<C> = <<C as Ciphersuite>::Group as Group>::Field;
+
+    let zero = F::<C>::zero();
+    let one = F::<C>::one();
+    let scalar = F::<C>::random(rng);
+
+    for s in [zero, one, scalar, zero - one] {
+        let encoded = F::<C>::serialize(&s);
+        assert!(F::<C>::deserialize(&encoded) == Ok(s));
+    }
+
+    let one_le = F::<C>::little_endian_serialize(&one);
+    let (first, rest) = one_le.as_ref().split_first().unwrap();
+    assert_eq!(*first, 1, "little_endian_serialize is not little-endian");
+    assert!(rest.iter().all(|b| *b == 0));
+
+    assert!(F::<C>::invert(&zero).is_err());
+    assert!(F::<C>::invert(&scalar).unwrap() * scalar == one);
+}
+
+/// Check that elements round-trip and that the identity is rejected when
+/// deserializing.
+fn check_group<C: Ciphersuite, R: RngCore + CryptoRng>(rng: &mut R) {
+    type F<C> = <<C as Ciphersuite>::Group as Group>::Field;
+
+    let generator = C::Group::generator();
+    let element = generator * F::<C>::random(rng);
+
+    for e in [generator, element] {
+        let encoded = C::Group::serialize(&e);
+        assert!(C::Group::deserialize(&encoded) == Ok(e));
+    }
+
+    let identity = C::Group::serialize(&C::Group::identity());
+    assert!(
+        C::Group::deserialize(&identity).is_err(),
+        "deserialize must reject the identity element"
+    );
+}
+
+/// Check the full signing flow, and that the resulting signature and verifying
+/// key round-trip.
+fn check_sign<C: Ciphersuite, R: RngCore + CryptoRng>(rng: &mut R) {
+    let (message, signature, verifying_key) = check_sign_with_dealer::<C, _>(&mut *rng);
+
+    let signature = Signature::<C>::deserialize(signature.serialize()).debugless_unwrap();
+    let verifying_key = VerifyingKey::<C>::deserialize(verifying_key.serialize()).unwrap();
+    assert!(verifying_key.verify(&message, &signature).is_ok());
+
+    let mut other_message = message;
+    other_message.push(0);
+    assert!(verifying_key.verify(&other_message, &signature).is_err());
+}
+
+/// Check that identifiers and key material round-trip.
+fn check_keys<C: Ciphersuite, R: RngCore + CryptoRng>(rng: &mut R) {
+    let (shares, pubkeys) =
+        keys::generate_with_dealer::<C, R>(5, 3, IdentifierList::Default, rng).unwrap();
+
+    for (identifier, share) in shares {
+        assert_eq!(
+            Identifier::<C>::deserialize(&identifier.serialize()).unwrap(),
+            identifier
+        );
+
+        let key_package = KeyPackage::try_from(share).unwrap();
+        let signing_share =
+            keys::SigningShare::<C>::deserialize(key_package.signing_share().serialize()).unwrap();
+        assert_eq!(&signing_share, key_package.signing_share());
+
+        #[cfg(feature = "serialization")]
+        assert_eq!(
+            KeyPackage::<C>::deserialize(&key_package.serialize().unwrap()).unwrap(),
+            key_package
+        );
+    }
+
+    #[cfg(feature = "serialization")]
+    assert_eq!(
+        keys::PublicKeyPackage::<C>::deserialize(&pubkeys.serialize().unwrap()).unwrap(),
+        pubkeys
+    );
+    #[cfg(not(feature = "serialization"))]
+    let _ = pubkeys;
+}
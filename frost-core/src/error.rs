@@ -4,6 +4,11 @@ use thiserror::Error;
 
 use crate::{Ciphersuite, Identifier};
 
+/// The version of the mapping from errors to codes returned by
+/// [`Error::code`], increased whenever a release adds codes.
+pub const ERROR_CODE_VERSION: u32 = 1;
+
+#[allow(dead_code)]
 #[derive(Error, Debug, Clone, Copy, Eq, PartialEq)]
 pub struct ParticipantError<C: Ciphersuite>(Identifier<C>);
 
@@ -44,6 +49,12 @@ pub enum Error<C: Ciphersuite> {
     /// Signature verification failed.
     #[error("Invalid signature.")]
     InvalidSignature,
+    /// The signature commitment `R` is the group identity.
+    #[error("The signature commitment is the identity.")]
+    IdentitySignatureCommitment,
+    /// The signature response `z` is zero.
+    #[error("The signature response is zero.")]
+    ZeroSignatureResponse,
     /// Duplicated shares provided
     #[error("Duplicated shares provided.")]
     DuplicatedShares,
@@ -89,6 +100,26 @@ pub enum Error<C: Ciphersuite> {
         /// The identifier of the signer whose share validation failed.
         culprit: Identifier<C>,
     },
+    /// The proof of possession of a share is not valid.
+    #[error("The proof of possession is not valid.")]
+    InvalidProofOfPossession {
+        /// The identifier of the signer whose proof of possession failed.
+        culprit: Identifier<C>,
+    },
+    /// A signature share was received from a signer without a commitment in
+    /// the signing package.
+    #[error("Signature share from a signer that is not in the signing package.")]
+    UnexpectedSigner {
+        /// The identifier of the unexpected signer.
+        culprit: Identifier<C>,
+    },
+    /// The group verifying keys of a key package and a public key package
+    /// differ.
+    #[error("The group verifying keys do not match.")]
+    GroupKeyMismatch,
+    /// The key package does not have the capability for the operation.
+    #[error("The key package does not have the capability for this operation.")]
+    MissingCapability,
     /// Error in scalar Field.
     #[error("Error in scalar Field.")]
     FieldError(#[from] FieldError),
@@ -107,6 +138,68 @@ pub enum Error<C: Ciphersuite> {
     /// Error deserializing value.
     #[error("Error deserializing value.")]
     DeserializationError,
+    /// The serialized value was produced by a different ciphersuite.
+    #[error("The serialized value belongs to a different ciphersuite.")]
+    CiphersuiteMismatch,
+    /// The serialized value uses a format version that is not supported.
+    #[error("Unsupported serialization format version.")]
+    UnsupportedVersion,
+    /// The message in the signing package does not match the agreed digest.
+    #[error("The message does not match the agreed message digest.")]
+    MessageDigestMismatch,
+    /// The number of signers exceeds the configured limit.
+    #[error("Too many signers.")]
+    TooManySigners,
+    /// A [`SignerBackend`](crate::round2::SignerBackend) failed to perform an
+    /// operation.
+    #[error("The signer backend failed.")]
+    SignerBackendFailure,
+    /// The public key package is internally inconsistent.
+    #[error("The public key package is inconsistent.")]
+    InconsistentPublicKeyPackage,
+    /// The message exceeds the configured length limit.
+    #[error("The message is too long.")]
+    MessageTooLong,
+    /// A signer's hiding and binding commitments are equal.
+    #[error("Degenerate signing commitment with equal hiding and binding commitments.")]
+    DegenerateCommitment {
+        /// The identifier of the signer whose commitment is degenerate.
+        culprit: Identifier<C>,
+    },
+    /// The signing nonces were zeroized, e.g. a copy of nonces discarded with
+    /// [`SigningNonces::abort`](crate::round1::SigningNonces::abort).
+    #[error("The signing nonces were aborted.")]
+    NoncesAborted,
+    /// A signing commitment is not signed by its signer.
+    #[error("The signing commitment signature is not valid.")]
+    InvalidCommitmentSignature {
+        /// The identifier of the signer whose commitment is not validly signed.
+        culprit: Identifier<C>,
+    },
+    /// Two signers published the same nonce commitment.
+    #[error("Colliding signing commitments from two signers.")]
+    CollidingCommitments {
+        /// The identifier of the first signer with the colliding commitment.
+        first: Identifier<C>,
+        /// The identifier of the second signer with the colliding commitment.
+        second: Identifier<C>,
+    },
+    /// Secret share verification failed, and the participant responsible for
+    /// the share is known.
+    #[error("Invalid secret share.")]
+    InvalidParticipantSecretShare {
+        /// The identifier of the participant whose secret share failed
+        /// verification, or who sent the invalid contribution to it.
+        culprit: Identifier<C>,
+    },
+    /// The signing share held by a
+    /// [`SignerBackend`](crate::round2::SignerBackend) doesn't match the
+    /// participant's verifying share.
+    #[error("The signer backend holds a different signing share.")]
+    SignerBackendMismatch,
+    /// There are fewer signature shares than the minimum number of signers.
+    #[error("Insufficient number of signature shares.")]
+    InsufficientShares,
 }
 
 impl<C> Error<C>
@@ -127,6 +220,21 @@ where
             }
             | Error::InvalidProofOfKnowledge {
                 culprit: identifier,
+            }
+            | Error::InvalidProofOfPossession {
+                culprit: identifier,
+            }
+            | Error::UnexpectedSigner {
+                culprit: identifier,
+            }
+            | Error::DegenerateCommitment {
+                culprit: identifier,
+            }
+            | Error::InvalidCommitmentSignature {
+                culprit: identifier,
+            }
+            | Error::InvalidParticipantSecretShare {
+                culprit: identifier,
             } => Some(*identifier),
             Error::InvalidSecretShare
             | Error::InvalidMinSigners
@@ -137,6 +245,8 @@ where
             | Error::MalformedVerifyingKey
             | Error::MalformedSignature
             | Error::InvalidSignature
+            | Error::IdentitySignatureCommitment
+            | Error::ZeroSignatureResponse
             | Error::DuplicatedShares
             | Error::IncorrectNumberOfShares
             | Error::IdentityCommitment
@@ -155,7 +265,142 @@ where
             | Error::IncorrectNumberOfCommitments
             | Error::SerializationError
             | Error::DeserializationError
-            | Error::IdentifierDerivationNotSupported => None,
+            | Error::CiphersuiteMismatch
+            | Error::UnsupportedVersion
+            | Error::MessageDigestMismatch
+            | Error::TooManySigners
+            | Error::SignerBackendFailure
+            | Error::SignerBackendMismatch
+            | Error::InsufficientShares
+            | Error::MissingCapability
+            | Error::GroupKeyMismatch
+            | Error::IdentifierDerivationNotSupported
+            | Error::InconsistentPublicKeyPackage
+            | Error::MessageTooLong
+            | Error::NoncesAborted
+            | Error::CollidingCommitments { .. } => None,
+        }
+    }
+
+    /// Return a stable numeric code for this error, e.g. for FFI callers
+    /// that need to `switch` on the error.
+    ///
+    /// Codes are never reused or reassigned; new errors get new codes. The
+    /// mapping version is [`ERROR_CODE_VERSION`]. Errors wrapping a
+    /// [`FieldError`] or a [`GroupError`] get a code per inner error.
+    ///
+    /// | Code | Error |
+    /// |------|-------|
+    /// | 1 | [`Error::InvalidMinSigners`] |
+    /// | 2 | [`Error::InvalidMaxSigners`] |
+    /// | 3 | [`Error::InvalidCoefficients`] |
+    /// | 4 | [`Error::MalformedIdentifier`] |
+    /// | 5 | [`Error::DuplicatedIdentifier`] |
+    /// | 6 | [`Error::UnknownIdentifier`] |
+    /// | 7 | [`Error::IncorrectNumberOfIdentifiers`] |
+    /// | 8 | [`Error::MalformedSigningKey`] |
+    /// | 9 | [`Error::MalformedVerifyingKey`] |
+    /// | 10 | [`Error::MalformedSignature`] |
+    /// | 11 | [`Error::InvalidSignature`] |
+    /// | 12 | [`Error::IdentitySignatureCommitment`] |
+    /// | 13 | [`Error::ZeroSignatureResponse`] |
+    /// | 14 | [`Error::DuplicatedShares`] |
+    /// | 15 | [`Error::IncorrectNumberOfShares`] |
+    /// | 16 | [`Error::IdentityCommitment`] |
+    /// | 17 | [`Error::MissingCommitment`] |
+    /// | 18 | [`Error::IncorrectCommitment`] |
+    /// | 19 | [`Error::IncorrectNumberOfCommitments`] |
+    /// | 20 | [`Error::InvalidSignatureShare`] |
+    /// | 21 | [`Error::InvalidSecretShare`] |
+    /// | 22 | [`Error::PackageNotFound`] |
+    /// | 23 | [`Error::IncorrectNumberOfPackages`] |
+    /// | 24 | [`Error::IncorrectPackage`] |
+    /// | 25 | [`Error::DKGNotSupported`] |
+    /// | 26 | [`Error::InvalidProofOfKnowledge`] |
+    /// | 27 | [`Error::InvalidProofOfPossession`] |
+    /// | 28 | [`Error::UnexpectedSigner`] |
+    /// | 29 | [`Error::GroupKeyMismatch`] |
+    /// | 30 | [`Error::MissingCapability`] |
+    /// | 31 | [`Error::InvalidCoefficient`] |
+    /// | 32 | [`Error::IdentifierDerivationNotSupported`] |
+    /// | 33 | [`Error::SerializationError`] |
+    /// | 34 | [`Error::DeserializationError`] |
+    /// | 35 | [`Error::CiphersuiteMismatch`] |
+    /// | 36 | [`Error::UnsupportedVersion`] |
+    /// | 37 | [`Error::MessageDigestMismatch`] |
+    /// | 38 | [`Error::TooManySigners`] |
+    /// | 39 | [`Error::SignerBackendFailure`] |
+    /// | 40 | [`Error::InconsistentPublicKeyPackage`] |
+    /// | 41 | [`Error::MessageTooLong`] |
+    /// | 42 | [`Error::DegenerateCommitment`] |
+    /// | 43 | [`Error::NoncesAborted`] |
+    /// | 44 | [`Error::InvalidCommitmentSignature`] |
+    /// | 45 | [`Error::CollidingCommitments`] |
+    /// | 46 | [`Error::InvalidParticipantSecretShare`] |
+    /// | 47 | [`Error::SignerBackendMismatch`] |
+    /// | 48 | [`Error::InsufficientShares`] |
+    /// | 1001 | [`FieldError::MalformedScalar`] |
+    /// | 1002 | [`FieldError::InvalidZeroScalar`] |
+    /// | 1101 | [`GroupError::MalformedElement`] |
+    /// | 1102 | [`GroupError::InvalidIdentityElement`] |
+    /// | 1103 | [`GroupError::InvalidNonPrimeOrderElement`] |
+    pub fn code(&self) -> u32 {
+        // Use an exhaustive match to make sure that if we add new enum items
+        // then we will explicitly assign them a code.
+        match self {
+            Error::InvalidMinSigners => 1,
+            Error::InvalidMaxSigners => 2,
+            Error::InvalidCoefficients => 3,
+            Error::MalformedIdentifier => 4,
+            Error::DuplicatedIdentifier => 5,
+            Error::UnknownIdentifier => 6,
+            Error::IncorrectNumberOfIdentifiers => 7,
+            Error::MalformedSigningKey => 8,
+            Error::MalformedVerifyingKey => 9,
+            Error::MalformedSignature => 10,
+            Error::InvalidSignature => 11,
+            Error::IdentitySignatureCommitment => 12,
+            Error::ZeroSignatureResponse => 13,
+            Error::DuplicatedShares => 14,
+            Error::IncorrectNumberOfShares => 15,
+            Error::IdentityCommitment => 16,
+            Error::MissingCommitment => 17,
+            Error::IncorrectCommitment => 18,
+            Error::IncorrectNumberOfCommitments => 19,
+            Error::InvalidSignatureShare { .. } => 20,
+            Error::InvalidSecretShare => 21,
+            Error::PackageNotFound => 22,
+            Error::IncorrectNumberOfPackages => 23,
+            Error::IncorrectPackage => 24,
+            Error::DKGNotSupported => 25,
+            Error::InvalidProofOfKnowledge { .. } => 26,
+            Error::InvalidProofOfPossession { .. } => 27,
+            Error::UnexpectedSigner { .. } => 28,
+            Error::GroupKeyMismatch => 29,
+            Error::MissingCapability => 30,
+            Error::InvalidCoefficient => 31,
+            Error::IdentifierDerivationNotSupported => 32,
+            Error::SerializationError => 33,
+            Error::DeserializationError => 34,
+            Error::CiphersuiteMismatch => 35,
+            Error::UnsupportedVersion => 36,
+            Error::MessageDigestMismatch => 37,
+            Error::TooManySigners => 38,
+            Error::SignerBackendFailure => 39,
+            Error::InconsistentPublicKeyPackage => 40,
+            Error::MessageTooLong => 41,
+            Error::DegenerateCommitment { .. } => 42,
+            Error::NoncesAborted => 43,
+            Error::InvalidCommitmentSignature { .. } => 44,
+            Error::CollidingCommitments { .. } => 45,
+            Error::InvalidParticipantSecretShare { .. } => 46,
+            Error::SignerBackendMismatch => 47,
+            Error::InsufficientShares => 48,
+            Error::FieldError(FieldError::MalformedScalar) => 1001,
+            Error::FieldError(FieldError::InvalidZeroScalar) => 1002,
+            Error::GroupError(GroupError::MalformedElement) => 1101,
+            Error::GroupError(GroupError::InvalidIdentityElement) => 1102,
+            Error::GroupError(GroupError::InvalidNonPrimeOrderElement) => 1103,
         }
     }
 }
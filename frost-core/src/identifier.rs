@@ -15,6 +15,10 @@ use crate::serialization::ScalarSerialization;
 /// The identifier is a field element in the scalar field that the secret polynomial is defined
 /// over, corresponding to some x-coordinate for a polynomial f(x) = y.  MUST NOT be zero in the
 /// field, as f(0) = the shared secret.
+///
+/// Identifiers are totally ordered by the integer value of their scalar, i.e.
+/// by comparing their little-endian encodings from the most significant byte.
+/// Thus identifiers created from `u16` values sort in numeric order.
 #[derive(Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
@@ -27,7 +31,7 @@ where
     C: Ciphersuite,
 {
     /// Create a new Identifier from a scalar. For internal use only.
-    fn new(scalar: Scalar<C>) -> Result<Self, Error<C>> {
+    pub(crate) fn new(scalar: Scalar<C>) -> Result<Self, Error<C>> {
         if scalar == <<C::Group as Group>::Field>::zero() {
             Err(FieldError::InvalidZeroScalar.into())
         } else {
@@ -49,6 +53,20 @@ where
         Self::new(scalar)
     }
 
+    /// Return the test identifier with value `n`.
+    ///
+    /// `test_identifier(1)` to `test_identifier(max_signers)` are the
+    /// identifiers used by [`IdentifierList::Default`](crate::keys::IdentifierList::Default).
+    /// Only meant for tests and demos.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, which is not a valid identifier.
+    #[cfg(feature = "test-vectors")]
+    pub fn test_identifier(n: u16) -> Self {
+        Self::try_from(n).expect("test identifiers start at 1")
+    }
+
     /// Serialize the identifier using the ciphersuite encoding.
     pub fn serialize(&self) -> <<C::Group as Group>::Field as Field>::Serialization {
         <<C::Group as Group>::Field>::serialize(&self.0)
@@ -117,6 +135,7 @@ impl<C> Ord for Identifier<C>
 where
     C: Ciphersuite,
 {
+    /// Compare identifiers by the integer value of their scalar.
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         let serialized_self = <<C::Group as Group>::Field>::little_endian_serialize(&self.0);
         let serialized_other = <<C::Group as Group>::Field>::little_endian_serialize(&other.0);
@@ -149,6 +168,9 @@ where
     }
 }
 
+// Newer compilers warn that `C` is not covered by a local type here, but
+// still accept the impl.
+#[allow(uncovered_param_in_projection)]
 impl<C> std::ops::MulAssign<Identifier<C>> for Scalar<C>
 where
     C: Ciphersuite,
@@ -195,3 +217,24 @@ where
         }
     }
 }
+
+impl<C> TryFrom<&[u8]> for Identifier<C>
+where
+    C: Ciphersuite,
+{
+    type Error = Error<C>;
+
+    /// Deserialize an identifier from its serialized scalar bytes, as returned
+    /// by [`Identifier::serialize`].
+    ///
+    /// Returns [`Error::MalformedIdentifier`] if the slice has the wrong
+    /// length, and an error if the bytes are not a canonical scalar encoding or
+    /// encode zero.
+    fn try_from(bytes: &[u8]) -> Result<Identifier<C>, Self::Error> {
+        let serialization = bytes
+            .to_vec()
+            .try_into()
+            .map_err(|_| Error::MalformedIdentifier)?;
+        Self::deserialize(&serialization)
+    }
+}
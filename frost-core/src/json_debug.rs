@@ -0,0 +1,113 @@
+//! Canonical JSON dumps of signing artifacts, for debugging.
+//!
+//! These are meant for diffing values across implementations (e.g. when a
+//! signer and a verifier written in different languages disagree), and are
+//! distinct from the `serde` wire serialization. All values are encoded as
+//! lowercase hex strings and object keys are emitted in sorted order.
+
+use itertools::Itertools;
+use serde_json::{Map, Value};
+
+use crate::{
+    round1::SigningCommitments, round2::SignatureShare, Ciphersuite, Field, Group, Signature,
+    SigningPackage,
+};
+
+/// Build a JSON object from `(key, value)` pairs, which must be sorted by key.
+fn object<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    debug_assert!(entries.iter().tuple_windows().all(|(a, b)| a.0 < b.0));
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<Map<_, _>>(),
+    )
+}
+
+fn hex_value(bytes: impl AsRef<[u8]>) -> Value {
+    Value::String(hex::encode(bytes))
+}
+
+impl<C> SigningCommitments<C>
+where
+    C: Ciphersuite,
+{
+    fn to_debug_value(self) -> Value {
+        object([
+            ("binding", hex_value(self.binding.serialize())),
+            ("hiding", hex_value(self.hiding.serialize())),
+        ])
+    }
+
+    /// Return a canonical JSON representation of these commitments, for
+    /// debugging purposes.
+    pub fn to_debug_json(&self) -> String {
+        object([
+            ("ciphersuite", Value::String(C::ID.to_string())),
+            ("commitments", self.to_debug_value()),
+        ])
+        .to_string()
+    }
+}
+
+impl<C> SigningPackage<C>
+where
+    C: Ciphersuite,
+{
+    /// Return a canonical JSON representation of this signing package, for
+    /// debugging purposes. Signing commitments are keyed by the hex-encoded
+    /// participant identifier.
+    pub fn to_debug_json(&self) -> String {
+        let signing_commitments: Map<_, _> = self
+            .signing_commitments()
+            .iter()
+            .map(|(identifier, commitments)| {
+                (
+                    hex::encode(identifier.serialize()),
+                    commitments.to_debug_value(),
+                )
+            })
+            .collect();
+
+        object([
+            ("ciphersuite", Value::String(C::ID.to_string())),
+            ("message", hex_value(self.message())),
+            ("signing_commitments", Value::Object(signing_commitments)),
+        ])
+        .to_string()
+    }
+}
+
+impl<C> SignatureShare<C>
+where
+    C: Ciphersuite,
+{
+    /// Return a canonical JSON representation of this signature share, for
+    /// debugging purposes.
+    pub fn to_debug_json(&self) -> String {
+        object([
+            ("ciphersuite", Value::String(C::ID.to_string())),
+            ("share", hex_value(self.serialize())),
+        ])
+        .to_string()
+    }
+}
+
+impl<C> Signature<C>
+where
+    C: Ciphersuite,
+{
+    /// Return a canonical JSON representation of this signature, for
+    /// debugging purposes.
+    pub fn to_debug_json(&self) -> String {
+        object([
+            ("R", hex_value(<C::Group>::serialize(&self.R))),
+            ("ciphersuite", Value::String(C::ID.to_string())),
+            (
+                "z",
+                hex_value(<<C::Group as Group>::Field>::serialize(&self.z)),
+            ),
+        ])
+        .to_string()
+    }
+}
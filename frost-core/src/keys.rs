@@ -12,16 +12,21 @@ use std::{
 use derive_getters::Getters;
 #[cfg(any(test, feature = "test-impl"))]
 use hex::FromHex;
+use itertools::Itertools;
 
-use rand_core::{CryptoRng, RngCore};
+use rand_chacha::ChaCha20Rng;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
 use zeroize::{DefaultIsZeroes, Zeroize};
 
 use crate::{
-    Ciphersuite, Element, Error, Field, Group, Header, Identifier, Scalar, SigningKey, VerifyingKey,
+    Ciphersuite, Element, Error, Field, Group, GroupError, Header, Identifier, Scalar, Signature,
+    SigningKey, VerifyingKey,
 };
 
 #[cfg(feature = "serde")]
-use crate::serialization::{ElementSerialization, ScalarSerialization};
+use crate::serialization::{next_field, ElementSerialization, ScalarSerialization, FORMAT_VERSION};
+#[cfg(feature = "serde")]
+use std::marker::PhantomData;
 
 #[cfg(feature = "serialization")]
 use crate::serialization::{Deserialize, Serialize};
@@ -116,6 +121,30 @@ where
         <<C::Group as Group>::Field>::serialize(&self.0)
     }
 
+    /// Return false if this share is suspiciously small, i.e. if either it or
+    /// its negation fits in fewer than `min_bits` bits.
+    ///
+    /// This is a heuristic sanity check that recipients of dealt shares can
+    /// run to detect e.g. a dealer with a broken RNG; a uniformly random share
+    /// fails it with negligible probability for any `min_bits` well below the
+    /// scalar size. It can't detect other kinds of bad randomness.
+    pub fn entropy_ok(&self, min_bits: u32) -> bool {
+        let bit_length = |scalar: &Scalar<C>| {
+            let bytes = <<C::Group as Group>::Field>::little_endian_serialize(scalar);
+            bytes
+                .as_ref()
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(_, byte)| **byte != 0)
+                .map_or(0, |(index, byte)| {
+                    8 * index as u32 + (8 - byte.leading_zeros())
+                })
+        };
+        let negated = <<C::Group as Group>::Field>::zero() - self.0;
+        bit_length(&self.0).min(bit_length(&negated)) >= min_bits
+    }
+
     /// Computes the signing share from a list of coefficients.
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     pub(crate) fn from_coefficients(coefficients: &[Scalar<C>], peer: Identifier<C>) -> Self {
@@ -417,6 +446,13 @@ where
     pub(crate) fn coefficients(&self) -> &[CoefficientCommitment<C>] {
         &self.0
     }
+
+    /// Return the threshold (`min_signers`) of the secret sharing, i.e. the
+    /// number of coefficient commitments, which is the degree of the secret
+    /// polynomial plus one. Saturates at `u16::MAX`.
+    pub fn threshold(&self) -> u16 {
+        u16::try_from(self.0.len()).unwrap_or(u16::MAX)
+    }
 }
 
 /// A secret share generated by performing a (t-out-of-n) secret sharing scheme,
@@ -490,6 +526,23 @@ where
 
         Ok((VerifyingShare(result), self.commitment.verifying_key()?))
     }
+
+    /// Like [`SecretShare::verify`], but first checks that the commitment is
+    /// for the agreed `min_signers`, returning
+    /// [`Error::IncorrectNumberOfCommitments`] otherwise.
+    ///
+    /// A dealer could otherwise use a polynomial of a different degree than
+    /// announced, changing the number of participants needed to sign.
+    pub fn verify_with_min_signers(
+        &self,
+        min_signers: u16,
+    ) -> Result<(VerifyingShare<C>, VerifyingKey<C>), Error<C>> {
+        if self.commitment.coefficients().len() != min_signers as usize {
+            return Err(Error::IncorrectNumberOfCommitments);
+        }
+
+        self.verify()
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -503,16 +556,25 @@ where
     }
 
     /// Deserialize the struct from a slice of bytes.
+    ///
+    /// Returns [`Error::UnsupportedVersion`] if the header has a format
+    /// version newer than the ones supported by this crate.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         Deserialize::deserialize(bytes)
     }
 }
 
 /// The identifier list to use when generating key shares.
+///
+/// The order of a custom list is irrelevant, since the generated shares are
+/// keyed by identifier. A custom list must not contain duplicates, which is
+/// checked up front by [`split`] and [`generate_with_dealer`]; the zero
+/// identifier can't be constructed, so it can't be part of the list.
 pub enum IdentifierList<'a, C: Ciphersuite> {
     /// Use the default values (1 to max_signers, inclusive).
     Default,
-    /// A user-provided list of identifiers.
+    /// A user-provided list of identifiers, which must have exactly
+    /// `max_signers` distinct elements.
     Custom(&'a [Identifier<C>]),
 }
 
@@ -556,16 +618,66 @@ pub fn split<C: Ciphersuite, R: RngCore + CryptoRng>(
 ) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
     validate_num_of_signers(min_signers, max_signers)?;
 
+    let coefficients = generate_coefficients::<C, R>(min_signers as usize - 1, rng);
+
+    split_with_coefficients(key, max_signers, min_signers, identifiers, coefficients)
+}
+
+/// Splits a key into FROST shares by evaluating the polynomial with the given
+/// coefficients, where `coefficients[0]` is the secret key and the others are
+/// the higher-degree coefficients, in increasing degree.
+///
+/// `coefficients` must have exactly `min_signers` elements, otherwise
+/// [`Error::InvalidCoefficients`] is returned.
+///
+/// This is only meant for constructing known-answer or adversarial test
+/// cases: the higher-degree coefficients must be uniformly random and secret
+/// for the shares to be secure, so use [`split`] otherwise.
+pub fn split_with_polynomial<C: Ciphersuite>(
+    coefficients: &[Scalar<C>],
+    max_signers: u16,
+    min_signers: u16,
+    identifiers: IdentifierList<C>,
+) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+    validate_num_of_signers(min_signers, max_signers)?;
+
+    let (secret, coefficients) = coefficients
+        .split_first()
+        .ok_or(Error::InvalidCoefficients)?;
+    if coefficients.len() != min_signers as usize - 1 {
+        return Err(Error::InvalidCoefficients);
+    }
+    let key = SigningKey::from_scalar(*secret);
+
+    split_with_coefficients(
+        &key,
+        max_signers,
+        min_signers,
+        identifiers,
+        coefficients.to_vec(),
+    )
+}
+
+/// Splits a key into FROST shares using the given `min_signers - 1`
+/// higher-degree coefficients.
+fn split_with_coefficients<C: Ciphersuite>(
+    key: &SigningKey<C>,
+    max_signers: u16,
+    min_signers: u16,
+    identifiers: IdentifierList<C>,
+    coefficients: Vec<Scalar<C>>,
+) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
     if let IdentifierList::Custom(identifiers) = &identifiers {
         if identifiers.len() != max_signers as usize {
             return Err(Error::IncorrectNumberOfIdentifiers);
         }
+        if identifiers.iter().collect::<BTreeSet<_>>().len() != identifiers.len() {
+            return Err(Error::DuplicatedIdentifier);
+        }
     }
 
     let verifying_key = VerifyingKey::from(key);
 
-    let coefficients = generate_coefficients::<C, R>(min_signers as usize - 1, rng);
-
     let secret_shares = match identifiers {
         IdentifierList::Default => {
             let identifiers = default_identifiers(max_signers);
@@ -592,10 +704,50 @@ pub fn split<C: Ciphersuite, R: RngCore + CryptoRng>(
             header: Header::default(),
             verifying_shares,
             verifying_key,
+            min_signers: Some(min_signers),
         },
     ))
 }
 
+/// Splits an existing key into FROST shares, deriving the secret polynomial
+/// from the given `seed` instead of a caller-provided RNG.
+///
+/// This is identical to [`split`] but seeds a [`ChaCha20Rng`] from `seed`, so
+/// that the same inputs always produce the same shares. This is useful for
+/// reproducible ceremonies such as disaster-recovery drills.
+///
+/// # Security
+///
+/// The seed is as sensitive as the key itself: it determines every
+/// coefficient of the secret polynomial, so anyone who learns the seed and a
+/// single share can recover the key. It MUST be generated uniformly at random,
+/// kept secret, and never reused for splitting another key.
+pub fn split_deterministic<C: Ciphersuite>(
+    key: &SigningKey<C>,
+    max_signers: u16,
+    min_signers: u16,
+    identifiers: IdentifierList<C>,
+    seed: [u8; 32],
+) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+    let mut rng = ChaCha20Rng::from_seed(seed);
+    split(key, max_signers, min_signers, identifiers, &mut rng)
+}
+
+/// Generate a known key set with [`generate_with_dealer`], using
+/// [`IdentifierList::Default`] and an RNG with a fixed seed.
+///
+/// The output only depends on `max_signers` and `min_signers`, so it is
+/// stable across runs. Only meant for tests and demos: the key is public
+/// knowledge.
+#[cfg(feature = "test-vectors")]
+pub fn test_dealer<C: Ciphersuite>(
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    generate_with_dealer(max_signers, min_signers, IdentifierList::Default, &mut rng)
+}
+
 /// Evaluate the polynomial with the given coefficients (constant term first)
 /// at the point x=identifier using Horner's method.
 ///
@@ -638,16 +790,99 @@ fn evaluate_vss<C: Ciphersuite>(
     result
 }
 
+/// The operations a [`KeyPackage`] may be used for.
+///
+/// This allows applications to restrict what a key package is used for, e.g.
+/// only for helping other participants repair their shares. Only
+/// [`Capabilities::SIGN`] is enforced by this crate: every function in
+/// [`round2`](crate::round2) that produces a signature share (including
+/// [`round2::sign_with_backend`](crate::round2::sign_with_backend), which
+/// takes it from the [`PublicKeyComponent`]) and
+/// [`round2::participation_receipt`](crate::round2::participation_receipt)
+/// refuse to sign without it. Signatures that don't contribute to a threshold
+/// signature, i.e. [`proof_of_possession`] and
+/// [`SigningCommitments::signed`](crate::round1::SigningCommitments::signed),
+/// are allowed regardless. Applications are responsible for checking the
+/// other capabilities.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8", into = "u8"))]
+pub struct Capabilities(u8);
+
+impl Capabilities {
+    /// Producing signature shares.
+    pub const SIGN: Self = Self(1);
+    /// Taking part in a DKG, e.g. to refresh shares.
+    pub const DKG: Self = Self(1 << 1);
+    /// Helping other participants repair their shares.
+    pub const REPAIR: Self = Self(1 << 2);
+    /// All capabilities.
+    pub const ALL: Self = Self(Self::SIGN.0 | Self::DKG.0 | Self::REPAIR.0);
+
+    /// Return the empty set of capabilities.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Return the raw bits of this set of capabilities.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Return true if all the capabilities in `other` are in this set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Return this set of capabilities without the ones in `other`.
+    pub const fn without(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl Default for Capabilities {
+    /// All capabilities, for backward compatibility.
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl std::ops::BitOr for Capabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl TryFrom<u8> for Capabilities {
+    type Error = &'static str;
+
+    /// Return the capabilities with the given raw bits, rejecting unknown bits.
+    fn try_from(bits: u8) -> Result<Self, Self::Error> {
+        if bits & !Self::ALL.0 != 0 {
+            return Err("unknown capability bits");
+        }
+        Ok(Self(bits))
+    }
+}
+
+impl From<Capabilities> for u8 {
+    fn from(capabilities: Capabilities) -> u8 {
+        capabilities.0
+    }
+}
+
 /// A FROST keypair, which can be generated either by a trusted dealer or using
 /// a DKG.
 ///
 /// When using a central dealer, [`SecretShare`]s are distributed to
 /// participants, who then perform verification, before deriving
 /// [`KeyPackage`]s, which they store to later use during signing.
+///
+/// Key packages with all [`Capabilities`] are serialized with format version
+/// 0, and can thus be read by previous versions of this crate.
 #[derive(Clone, Debug, PartialEq, Eq, Getters, Zeroize)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
-#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct KeyPackage<C: Ciphersuite> {
     /// Serialization header
     #[getter(skip)]
@@ -664,6 +899,10 @@ pub struct KeyPackage<C: Ciphersuite> {
     #[zeroize(skip)]
     pub(crate) verifying_key: VerifyingKey<C>,
     pub(crate) min_signers: u16,
+    /// The operations this key package may be used for. Added in format
+    /// version 1.
+    #[zeroize(skip)]
+    pub(crate) capabilities: Capabilities,
 }
 
 impl<C> KeyPackage<C>
@@ -685,7 +924,201 @@ where
             verifying_share,
             verifying_key,
             min_signers,
+            capabilities: Capabilities::default(),
+        }
+    }
+
+    /// Return this [`KeyPackage`] restricted to the given [`Capabilities`].
+    ///
+    /// Key packages have all capabilities by default.
+    pub fn with_capabilities(self, capabilities: Capabilities) -> Self {
+        Self {
+            capabilities,
+            ..self
+        }
+    }
+
+    /// Check that the given [`PublicKeyPackage`], e.g. received from the
+    /// coordinator, belongs to the same group as this [`KeyPackage`].
+    ///
+    /// Returns [`Error::GroupKeyMismatch`] if their group verifying keys
+    /// differ, which indicates a configuration error or an attack.
+    pub fn check_group(&self, pubkeys: &PublicKeyPackage<C>) -> Result<(), Error<C>> {
+        if self.verifying_key != pubkeys.verifying_key {
+            return Err(Error::GroupKeyMismatch);
         }
+        Ok(())
+    }
+
+    /// Return the public portion of this [`KeyPackage`], which omits the
+    /// secret signing share and can be safely shared with other participants.
+    pub fn public_part(&self) -> PublicKeyComponent<C> {
+        PublicKeyComponent::new(
+            self.identifier,
+            self.verifying_share,
+            self.verifying_key,
+            self.min_signers,
+        )
+        .with_capabilities(self.capabilities)
+    }
+}
+
+/// Return the header to serialize a value with the given capabilities with:
+/// format version 0 if it has all of them, since the field was added in
+/// version 1.
+#[cfg(feature = "serde")]
+fn capabilities_header<C: Ciphersuite>(capabilities: Capabilities) -> Header<C> {
+    let version = if capabilities == Capabilities::ALL {
+        0
+    } else {
+        FORMAT_VERSION
+    };
+    Header {
+        version,
+        ..Header::default()
+    }
+}
+
+/// Deserialize the capabilities at the end of a version 1 value from a
+/// sequence, or return all of them for version 0.
+#[cfg(feature = "serde")]
+fn capabilities_field<'de, A, C>(
+    seq: &mut A,
+    header: &Header<C>,
+    index: usize,
+    expected: &dyn serde::de::Expected,
+) -> Result<Capabilities, A::Error>
+where
+    A: serde::de::SeqAccess<'de>,
+    C: Ciphersuite,
+{
+    match header.version {
+        0 => Ok(Capabilities::ALL),
+        _ => next_field(seq, index, expected),
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> serde::Serialize for KeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let header = capabilities_header::<C>(self.capabilities);
+        let mut state = serializer.serialize_struct("KeyPackage", 7)?;
+        state.serialize_field("header", &header)?;
+        state.serialize_field("identifier", &self.identifier)?;
+        state.serialize_field("signing_share", &self.signing_share)?;
+        state.serialize_field("verifying_share", &self.verifying_share)?;
+        state.serialize_field("verifying_key", &self.verifying_key)?;
+        state.serialize_field("min_signers", &self.min_signers)?;
+        if header.version == 0 {
+            state.skip_field("capabilities")?;
+        } else {
+            state.serialize_field("capabilities", &self.capabilities)?;
+        }
+        state.end()
+    }
+}
+
+/// The fields of a [`KeyPackage`] in self-describing formats, where
+/// `capabilities` is simply omitted if it has all of them.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct KeyPackageFields<C: Ciphersuite> {
+    #[allow(dead_code)]
+    header: Header<C>,
+    identifier: Identifier<C>,
+    signing_share: SigningShare<C>,
+    verifying_share: VerifyingShare<C>,
+    verifying_key: VerifyingKey<C>,
+    min_signers: u16,
+    #[serde(default)]
+    capabilities: Capabilities,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::Deserialize<'de> for KeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<C>(PhantomData<C>);
+
+        impl<'de, C> serde::de::Visitor<'de> for Visitor<C>
+        where
+            C: Ciphersuite,
+        {
+            type Value = KeyPackage<C>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct KeyPackage")
+            }
+
+            // Used by formats that aren't self-describing (e.g. postcard),
+            // where the header version tells which fields are present.
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let header: Header<C> = next_field(&mut seq, 0, &self)?;
+                let identifier = next_field(&mut seq, 1, &self)?;
+                let signing_share = next_field(&mut seq, 2, &self)?;
+                let verifying_share = next_field(&mut seq, 3, &self)?;
+                let verifying_key = next_field(&mut seq, 4, &self)?;
+                let min_signers = next_field(&mut seq, 5, &self)?;
+                let capabilities = capabilities_field(&mut seq, &header, 6, &self)?;
+                Ok(KeyPackage::new(
+                    identifier,
+                    signing_share,
+                    verifying_share,
+                    verifying_key,
+                    min_signers,
+                )
+                .with_capabilities(capabilities))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let fields: KeyPackageFields<C> = serde::Deserialize::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                Ok(KeyPackage::new(
+                    fields.identifier,
+                    fields.signing_share,
+                    fields.verifying_share,
+                    fields.verifying_key,
+                    fields.min_signers,
+                )
+                .with_capabilities(fields.capabilities))
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KeyPackage",
+            &[
+                "header",
+                "identifier",
+                "signing_share",
+                "verifying_share",
+                "verifying_key",
+                "min_signers",
+                "capabilities",
+            ],
+            Visitor(PhantomData),
+        )
     }
 }
 
@@ -699,6 +1132,200 @@ where
         Serialize::serialize(&self)
     }
 
+    /// Deserialize the struct from a slice of bytes.
+    ///
+    /// Returns [`Error::UnsupportedVersion`] if the header has a format
+    /// version newer than the ones supported by this crate. Key packages
+    /// serialized with any supported format version can be read.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+}
+
+/// The public portion of a [`KeyPackage`]: everything except the secret
+/// [`SigningShare`].
+///
+/// This can be sent to other participants (e.g. to assemble a
+/// [`PublicKeyPackage`]) without risking disclosure of the signing share.
+///
+/// Like [`KeyPackage`], components with all [`Capabilities`] are serialized
+/// with format version 0.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+pub struct PublicKeyComponent<C: Ciphersuite> {
+    /// Serialization header
+    #[getter(skip)]
+    pub(crate) header: Header<C>,
+    /// Denotes the participant identifier this component belongs to.
+    pub(crate) identifier: Identifier<C>,
+    /// The participant's public key.
+    pub(crate) verifying_share: VerifyingShare<C>,
+    /// The public verifying key that represents the entire group.
+    pub(crate) verifying_key: VerifyingKey<C>,
+    pub(crate) min_signers: u16,
+    /// The operations the key package may be used for.
+    pub(crate) capabilities: Capabilities,
+}
+
+impl<C> PublicKeyComponent<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`PublicKeyComponent`] instance, with all capabilities.
+    pub fn new(
+        identifier: Identifier<C>,
+        verifying_share: VerifyingShare<C>,
+        verifying_key: VerifyingKey<C>,
+        min_signers: u16,
+    ) -> Self {
+        Self {
+            header: Header::default(),
+            identifier,
+            verifying_share,
+            verifying_key,
+            min_signers,
+            capabilities: Capabilities::default(),
+        }
+    }
+
+    /// Return this [`PublicKeyComponent`] restricted to the given
+    /// [`Capabilities`].
+    pub fn with_capabilities(self, capabilities: Capabilities) -> Self {
+        Self {
+            capabilities,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> serde::Serialize for PublicKeyComponent<C>
+where
+    C: Ciphersuite,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let header = capabilities_header::<C>(self.capabilities);
+        let mut state = serializer.serialize_struct("PublicKeyComponent", 6)?;
+        state.serialize_field("header", &header)?;
+        state.serialize_field("identifier", &self.identifier)?;
+        state.serialize_field("verifying_share", &self.verifying_share)?;
+        state.serialize_field("verifying_key", &self.verifying_key)?;
+        state.serialize_field("min_signers", &self.min_signers)?;
+        if header.version == 0 {
+            state.skip_field("capabilities")?;
+        } else {
+            state.serialize_field("capabilities", &self.capabilities)?;
+        }
+        state.end()
+    }
+}
+
+/// The fields of a [`PublicKeyComponent`] in self-describing formats, where
+/// `capabilities` is simply omitted if it has all of them.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct PublicKeyComponentFields<C: Ciphersuite> {
+    #[allow(dead_code)]
+    header: Header<C>,
+    identifier: Identifier<C>,
+    verifying_share: VerifyingShare<C>,
+    verifying_key: VerifyingKey<C>,
+    min_signers: u16,
+    #[serde(default)]
+    capabilities: Capabilities,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::Deserialize<'de> for PublicKeyComponent<C>
+where
+    C: Ciphersuite,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<C>(PhantomData<C>);
+
+        impl<'de, C> serde::de::Visitor<'de> for Visitor<C>
+        where
+            C: Ciphersuite,
+        {
+            type Value = PublicKeyComponent<C>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PublicKeyComponent")
+            }
+
+            // See the `KeyPackage` implementation.
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let header: Header<C> = next_field(&mut seq, 0, &self)?;
+                let identifier = next_field(&mut seq, 1, &self)?;
+                let verifying_share = next_field(&mut seq, 2, &self)?;
+                let verifying_key = next_field(&mut seq, 3, &self)?;
+                let min_signers = next_field(&mut seq, 4, &self)?;
+                let capabilities = capabilities_field(&mut seq, &header, 5, &self)?;
+                Ok(
+                    PublicKeyComponent::new(
+                        identifier,
+                        verifying_share,
+                        verifying_key,
+                        min_signers,
+                    )
+                    .with_capabilities(capabilities),
+                )
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let fields: PublicKeyComponentFields<C> = serde::Deserialize::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                Ok(PublicKeyComponent::new(
+                    fields.identifier,
+                    fields.verifying_share,
+                    fields.verifying_key,
+                    fields.min_signers,
+                )
+                .with_capabilities(fields.capabilities))
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PublicKeyComponent",
+            &[
+                "header",
+                "identifier",
+                "verifying_share",
+                "verifying_key",
+                "min_signers",
+                "capabilities",
+            ],
+            Visitor(PhantomData),
+        )
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> PublicKeyComponent<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
     /// Deserialize the struct from a slice of bytes.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         Deserialize::deserialize(bytes)
@@ -728,19 +1355,91 @@ where
             signing_share: secret_share.signing_share,
             verifying_share,
             verifying_key,
-            min_signers: secret_share.commitment.0.len() as u16,
+            min_signers: secret_share.commitment.threshold(),
+            capabilities: Capabilities::default(),
         })
     }
 }
 
+/// Verify all the [`SecretShare`]s generated by a dealer and convert them
+/// into [`KeyPackage`]s.
+///
+/// Returns [`Error::InvalidParticipantSecretShare`] naming the offending
+/// identifier if any share fails verification.
+pub fn key_packages<C: Ciphersuite>(
+    shares: &BTreeMap<Identifier<C>, SecretShare<C>>,
+) -> Result<BTreeMap<Identifier<C>, KeyPackage<C>>, Error<C>> {
+    shares
+        .iter()
+        .map(|(identifier, share)| {
+            let key_package = KeyPackage::try_from(share.clone()).map_err(|e| match e {
+                Error::InvalidSecretShare => Error::InvalidParticipantSecretShare {
+                    culprit: *identifier,
+                },
+                e => e,
+            })?;
+            Ok((*identifier, key_package))
+        })
+        .collect()
+}
+
+/// Build the message signed by a proof of possession, binding it to the
+/// ciphersuite, the participant identifier and their verifying share.
+fn proof_of_possession_message<C: Ciphersuite>(
+    identifier: &Identifier<C>,
+    verifying_share: &VerifyingShare<C>,
+) -> Vec<u8> {
+    let mut message = Vec::new();
+    message.extend_from_slice(C::ID.as_bytes());
+    message.extend_from_slice(b"proof-of-possession");
+    message.extend_from_slice(identifier.serialize().as_ref());
+    message.extend_from_slice(verifying_share.serialize().as_ref());
+    message
+}
+
+/// Compute a proof that the holder of the given [`KeyPackage`] possesses its
+/// signing share.
+///
+/// The proof is a Schnorr signature, made with the signing share, over the
+/// participant identifier and verifying share. A coordinator can check it with
+/// [`verify_proof_of_possession`] before accepting a participant, which
+/// prevents rogue-key attacks.
+pub fn proof_of_possession<C: Ciphersuite, R: RngCore + CryptoRng>(
+    key_package: &KeyPackage<C>,
+    rng: &mut R,
+) -> Signature<C> {
+    let signing_key = SigningKey::from_scalar(key_package.signing_share.0);
+    let message =
+        proof_of_possession_message(&key_package.identifier, &key_package.verifying_share);
+    signing_key.sign(rng, &message)
+}
+
+/// Verify a proof of possession computed with [`proof_of_possession`].
+///
+/// Returns [`Error::InvalidProofOfPossession`] with the given identifier as
+/// the culprit if the proof is not valid for the verifying share.
+pub fn verify_proof_of_possession<C: Ciphersuite>(
+    verifying_share: &VerifyingShare<C>,
+    identifier: Identifier<C>,
+    proof_of_possession: &Signature<C>,
+) -> Result<(), Error<C>> {
+    let message = proof_of_possession_message(&identifier, verifying_share);
+    VerifyingKey::new(verifying_share.0)
+        .verify(&message, proof_of_possession)
+        .map_err(|_| Error::InvalidProofOfPossession {
+            culprit: identifier,
+        })
+}
+
 /// Public data that contains all the signers' verifying shares as well as the
 /// group verifying key.
 ///
 /// Used for verification purposes before publishing a signature.
+///
+/// Packages that don't record the minimum number of signers are serialized
+/// with format version 0, and can thus be read by previous versions of this
+/// crate.
 #[derive(Clone, Debug, PartialEq, Eq, Getters)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
-#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct PublicKeyPackage<C: Ciphersuite> {
     /// Serialization header
     #[getter(skip)]
@@ -750,6 +1449,9 @@ pub struct PublicKeyPackage<C: Ciphersuite> {
     pub(crate) verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
     /// The joint public key for the entire group.
     pub(crate) verifying_key: VerifyingKey<C>,
+    /// The minimum number of signers, if known. Added in format version 1.
+    #[getter(skip)]
+    pub(crate) min_signers: Option<u16>,
 }
 
 impl<C> PublicKeyPackage<C>
@@ -757,15 +1459,99 @@ where
     C: Ciphersuite,
 {
     /// Create a new [`PublicKeyPackage`] instance.
+    ///
+    /// The package will not record the minimum number of signers; packages
+    /// generated by [`generate_with_dealer`], [`split`] or the DKG do.
     pub fn new(
         verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
         verifying_key: VerifyingKey<C>,
+    ) -> Self {
+        Self::new_internal(verifying_shares, verifying_key, None)
+    }
+
+    /// Create a new [`PublicKeyPackage`] instance, which records the given
+    /// minimum number of signers if any.
+    #[cfg_attr(feature = "internals", visibility::make(pub))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
+    pub(crate) fn new_internal(
+        verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
+        verifying_key: VerifyingKey<C>,
+        min_signers: Option<u16>,
     ) -> Self {
         Self {
             header: Header::default(),
             verifying_shares,
             verifying_key,
+            min_signers,
+        }
+    }
+
+    /// Return the minimum number of signers required to produce a signature,
+    /// or `None` if the package was created without it (see
+    /// [`PublicKeyPackage::new`]).
+    pub fn min_signers(&self) -> Option<u16> {
+        self.min_signers
+    }
+
+    /// Return the number of signers in the group, i.e. the number of
+    /// verifying shares.
+    pub fn max_signers(&self) -> u16 {
+        self.verifying_shares.len() as u16
+    }
+
+    /// Return an iterator over all the minimal signing quorums, i.e. every set
+    /// of exactly `min_signers` participants of this package.
+    ///
+    /// The quorums are generated lazily, in lexicographic order of the
+    /// identifiers. Returns [`Error::InvalidMinSigners`] if the package does
+    /// not record the minimum number of signers (see
+    /// [`PublicKeyPackage::min_signers`]).
+    pub fn minimal_quorums(
+        &self,
+    ) -> Result<impl Iterator<Item = BTreeSet<Identifier<C>>> + '_, Error<C>> {
+        let min_signers = self.min_signers.ok_or(Error::InvalidMinSigners)?;
+        Ok(self
+            .verifying_shares
+            .keys()
+            .copied()
+            .combinations(min_signers as usize)
+            .map(BTreeSet::from_iter))
+    }
+
+    /// Return a copy of this package with only the verifying shares of the
+    /// given signers, e.g. for a coordinator that only works with a fixed
+    /// subset of the group. The group verifying key and the minimum number of
+    /// signers are kept.
+    ///
+    /// Returns [`Error::UnknownIdentifier`] if some identifier has no verifying
+    /// share in this package, and [`Error::IncorrectNumberOfIdentifiers`] if
+    /// fewer identifiers than the minimum number of signers (if known) are
+    /// given, since the restricted package could not be used to aggregate.
+    pub fn restrict_to(
+        &self,
+        identifiers: &BTreeSet<Identifier<C>>,
+    ) -> Result<PublicKeyPackage<C>, Error<C>> {
+        if let Some(min_signers) = self.min_signers {
+            if identifiers.len() < min_signers as usize {
+                return Err(Error::IncorrectNumberOfIdentifiers);
+            }
         }
+
+        let verifying_shares = identifiers
+            .iter()
+            .map(|id| {
+                self.verifying_shares
+                    .get(id)
+                    .map(|verifying_share| (*id, *verifying_share))
+                    .ok_or(Error::UnknownIdentifier)
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+        Ok(PublicKeyPackage::new_internal(
+            verifying_shares,
+            self.verifying_key,
+            self.min_signers,
+        ))
     }
 
     /// Computes the public key package given a list of participant identifiers
@@ -780,9 +1566,10 @@ where
             .iter()
             .map(|id| (*id, VerifyingShare::from_commitment(*id, commitment)))
             .collect();
-        Ok(PublicKeyPackage::new(
+        Ok(PublicKeyPackage::new_internal(
             verifying_keys,
             VerifyingKey::from_commitment(commitment)?,
+            Some(commitment.threshold()),
         ))
     }
 
@@ -799,6 +1586,169 @@ where
         let group_commitment = sum_commitments(&commitments)?;
         Self::from_commitment(&identifiers, &group_commitment)
     }
+
+    /// Check the internal consistency of this package for the given threshold.
+    ///
+    /// This verifies that the verifying shares are all distinct and none of
+    /// them is the identity, and that they all lie on a single polynomial of
+    /// degree `min_signers - 1` whose value at zero is the group verifying key.
+    /// The latter means that interpolating the verifying shares of any
+    /// `min_signers` participants yields the group verifying key.
+    ///
+    /// This can be used to catch a corrupted package before signing.
+    pub fn audit(&self, min_signers: u16) -> Result<(), Error<C>> {
+        validate_num_of_signers(min_signers, self.verifying_shares.len() as u16)?;
+
+        let identity = <C::Group>::identity();
+        let mut seen = HashSet::new();
+        for verifying_share in self.verifying_shares.values() {
+            if verifying_share.0 == identity {
+                return Err(GroupError::InvalidIdentityElement.into());
+            }
+            if !seen.insert(verifying_share.serialize().as_ref().to_vec()) {
+                return Err(Error::DuplicatedShares);
+            }
+        }
+
+        // Interpolate the polynomial (in the exponent) from the first
+        // `min_signers` shares and check that it matches the group verifying
+        // key and every remaining share.
+        let (base, rest): (Vec<_>, Vec<_>) = self
+            .verifying_shares
+            .iter()
+            .enumerate()
+            .partition(|(i, _)| *i < min_signers as usize);
+        let base_identifiers: BTreeSet<_> = base.iter().map(|(_, (id, _))| **id).collect();
+        let interpolate = |x: Option<Identifier<C>>| -> Result<Element<C>, Error<C>> {
+            let mut value = <C::Group>::identity();
+            for (_, (id, verifying_share)) in &base {
+                let lambda = compute_lagrange_coefficient(&base_identifiers, x, **id)?;
+                value = value + verifying_share.0 * lambda;
+            }
+            Ok(value)
+        };
+
+        if interpolate(None)? != self.verifying_key.element {
+            return Err(Error::InconsistentPublicKeyPackage);
+        }
+        for (_, (id, verifying_share)) in rest {
+            if interpolate(Some(*id))? != verifying_share.0 {
+                return Err(Error::InconsistentPublicKeyPackage);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> serde::Serialize for PublicKeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let version = match self.min_signers {
+            Some(_) => FORMAT_VERSION,
+            None => 0,
+        };
+        let header = Header::<C> {
+            version,
+            ..Header::default()
+        };
+        let mut state = serializer.serialize_struct("PublicKeyPackage", 4)?;
+        state.serialize_field("header", &header)?;
+        state.serialize_field("verifying_shares", &self.verifying_shares)?;
+        state.serialize_field("verifying_key", &self.verifying_key)?;
+        match self.min_signers {
+            Some(min_signers) => state.serialize_field("min_signers", &min_signers)?,
+            None => state.skip_field("min_signers")?,
+        }
+        state.end()
+    }
+}
+
+/// The fields of a [`PublicKeyPackage`] in self-describing formats, where
+/// `min_signers` is simply omitted if unknown.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct PublicKeyPackageFields<C: Ciphersuite> {
+    #[allow(dead_code)]
+    header: Header<C>,
+    verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
+    verifying_key: VerifyingKey<C>,
+    #[serde(default)]
+    min_signers: Option<u16>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::Deserialize<'de> for PublicKeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<C>(PhantomData<C>);
+
+        impl<'de, C> serde::de::Visitor<'de> for Visitor<C>
+        where
+            C: Ciphersuite,
+        {
+            type Value = PublicKeyPackage<C>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PublicKeyPackage")
+            }
+
+            // Used by formats that aren't self-describing (e.g. postcard),
+            // where the header version tells which fields are present.
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let header: Header<C> = next_field(&mut seq, 0, &self)?;
+                let verifying_shares = next_field(&mut seq, 1, &self)?;
+                let verifying_key = next_field(&mut seq, 2, &self)?;
+                let min_signers = match header.version {
+                    0 => None,
+                    _ => Some(next_field(&mut seq, 3, &self)?),
+                };
+                Ok(PublicKeyPackage::new_internal(
+                    verifying_shares,
+                    verifying_key,
+                    min_signers,
+                ))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let fields: PublicKeyPackageFields<C> = serde::Deserialize::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                Ok(PublicKeyPackage::new_internal(
+                    fields.verifying_shares,
+                    fields.verifying_key,
+                    fields.min_signers,
+                ))
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PublicKeyPackage",
+            &["header", "verifying_shares", "verifying_key", "min_signers"],
+            Visitor(PhantomData),
+        )
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -817,6 +1767,95 @@ where
     }
 }
 
+/// The magic bytes at the start of a serialized [`KeySet`].
+pub const KEYSET_MAGIC: [u8; 8] = *b"FROSTKEY";
+
+/// The public state of a FROST group, bundled for distribution to all nodes:
+/// the [`PublicKeyPackage`], the threshold parameters and some metadata.
+///
+/// It does not contain any secret.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct KeySet<C: Ciphersuite> {
+    /// Serialization header
+    #[getter(skip)]
+    pub(crate) header: Header<C>,
+    /// The public key package of the group.
+    public_key_package: PublicKeyPackage<C>,
+    /// The minimum number of signers.
+    min_signers: u16,
+    /// The total number of participants.
+    max_signers: u16,
+    /// The creation time, in seconds since the Unix epoch.
+    created_at: u64,
+    /// A human-readable label for the group.
+    label: String,
+}
+
+impl<C> KeySet<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`KeySet`].
+    ///
+    /// Returns an error if the threshold parameters are invalid, or if
+    /// `public_key_package` records a different minimum number of signers or
+    /// has more than `max_signers` verifying shares.
+    pub fn new(
+        public_key_package: PublicKeyPackage<C>,
+        min_signers: u16,
+        max_signers: u16,
+        created_at: u64,
+        label: String,
+    ) -> Result<Self, Error<C>> {
+        validate_num_of_signers(min_signers, max_signers)?;
+        if public_key_package
+            .min_signers()
+            .is_some_and(|m| m != min_signers)
+        {
+            return Err(Error::InvalidMinSigners);
+        }
+        if public_key_package.verifying_shares().len() > max_signers as usize {
+            return Err(Error::InvalidMaxSigners);
+        }
+
+        Ok(Self {
+            header: Header::default(),
+            public_key_package,
+            min_signers,
+            max_signers,
+            created_at,
+            label,
+        })
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> KeySet<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec, starting with [`KEYSET_MAGIC`].
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        let mut bytes = KEYSET_MAGIC.to_vec();
+        bytes.extend(Serialize::serialize(&self)?);
+        Ok(bytes)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    ///
+    /// Returns [`Error::DeserializationError`] if the bytes don't start with
+    /// [`KEYSET_MAGIC`].
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        let bytes = bytes
+            .strip_prefix(&KEYSET_MAGIC)
+            .ok_or(Error::DeserializationError)?;
+        Deserialize::deserialize(bytes)
+    }
+}
+
 fn validate_num_of_signers<C: Ciphersuite>(
     min_signers: u16,
     max_signers: u16,
@@ -920,6 +1959,68 @@ pub(crate) fn generate_secret_shares<C: Ciphersuite>(
     Ok(secret_shares)
 }
 
+/// Check whether the given [`KeyPackage`]s are sufficient to [`reconstruct`]
+/// the secret, without actually reconstructing it.
+///
+/// Returns [`Error::IncorrectNumberOfShares`] if fewer than `min_signers`
+/// packages are provided, and [`Error::DuplicatedIdentifier`] if the
+/// packages do not have distinct identifiers.
+pub fn can_reconstruct<C: Ciphersuite>(
+    key_packages: &[KeyPackage<C>],
+    min_signers: u16,
+) -> Result<(), Error<C>> {
+    if key_packages.is_empty() || key_packages.len() < min_signers as usize {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+
+    let identifiers: BTreeSet<_> = key_packages.iter().map(|s| s.identifier()).collect();
+    if identifiers.len() != key_packages.len() {
+        return Err(Error::DuplicatedIdentifier);
+    }
+
+    Ok(())
+}
+
+/// Recompute the group [`VerifyingKey`] from the public parts of at least
+/// `min_signers` participants' [`KeyPackage`]s (see
+/// [`KeyPackage::public_part`]), using Lagrange interpolation in the exponent
+/// over their [`VerifyingShare`]s.
+///
+/// This allows a quorum to bootstrap a [`PublicKeyPackage`] by exchanging only
+/// public data, when no dealer-published one is available.
+///
+/// Returns [`Error::IncorrectNumberOfShares`] if fewer than `min_signers`
+/// components are provided, [`Error::DuplicatedIdentifier`] if they do not
+/// have distinct identifiers, and [`Error::GroupKeyMismatch`] if the
+/// interpolated key differs from the group key claimed by any component.
+pub fn group_key_from_packages<C: Ciphersuite>(
+    packages: &[PublicKeyComponent<C>],
+    min_signers: u16,
+) -> Result<VerifyingKey<C>, Error<C>> {
+    if packages.is_empty() || packages.len() < min_signers as usize {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+
+    let identifiers: BTreeSet<_> = packages.iter().map(|p| p.identifier).collect();
+    if identifiers.len() != packages.len() {
+        return Err(Error::DuplicatedIdentifier);
+    }
+
+    let mut element = <C::Group>::identity();
+    for package in packages {
+        let lagrange_coefficient =
+            compute_lagrange_coefficient(&identifiers, None, package.identifier)?;
+        element = element + package.verifying_share.0 * lagrange_coefficient;
+    }
+    let verifying_key = VerifyingKey::new(element);
+
+    if packages.iter().any(|p| p.verifying_key != verifying_key) {
+        return Err(Error::GroupKeyMismatch);
+    }
+
+    Ok(verifying_key)
+}
+
 /// Recompute the secret from at least `min_signers` secret shares (inside
 /// [`KeyPackage`]s) using Lagrange interpolation.
 ///
@@ -931,7 +2032,8 @@ pub(crate) fn generate_secret_shares<C: Ciphersuite>(
 /// reconstruct the original key.
 ///
 /// The caller is responsible for providing at least `min_signers` packages;
-/// if less than that is provided, a different key will be returned.
+/// if less than that is provided, a different key will be returned. Use
+/// [`can_reconstruct`] to check this beforehand.
 pub fn reconstruct<C: Ciphersuite>(
     key_packages: &[KeyPackage<C>],
 ) -> Result<SigningKey<C>, Error<C>> {
@@ -947,9 +2049,7 @@ pub fn reconstruct<C: Ciphersuite>(
         .map(|k| k.min_signers)
         .min()
         .expect("should not be empty since that was just tested");
-    if key_packages.len() < min_signers as usize {
-        return Err(Error::IncorrectNumberOfShares);
-    }
+    can_reconstruct(key_packages, min_signers)?;
 
     let mut secret = <<C::Group as Group>::Field>::zero();
 
@@ -959,10 +2059,6 @@ pub fn reconstruct<C: Ciphersuite>(
         .cloned()
         .collect();
 
-    if identifiers.len() != key_packages.len() {
-        return Err(Error::DuplicatedIdentifier);
-    }
-
     // Compute the Lagrange coefficients
     for key_package in key_packages.iter() {
         let lagrange_coefficient =
@@ -974,3 +2070,83 @@ pub fn reconstruct<C: Ciphersuite>(
 
     Ok(SigningKey { scalar: secret })
 }
+
+/// Evaluate the secret polynomial at `point` from at least `min_signers`
+/// secret shares (inside [`KeyPackage`]s) using Lagrange interpolation.
+///
+/// If `point` is the identifier of some participant, this returns their
+/// signing share, which is the core of share repair and enrollment. Note that
+/// evaluating at zero returns the group secret, like [`reconstruct`].
+///
+/// The caller is responsible for providing at least `min_signers` packages;
+/// if less than that is provided, a wrong value will be returned. Returns
+/// [`Error::IncorrectNumberOfShares`] if `key_packages` is empty, and
+/// [`Error::DuplicatedIdentifier`] if it has duplicate identifiers.
+pub fn evaluate_at<C: Ciphersuite>(
+    key_packages: &[KeyPackage<C>],
+    point: Scalar<C>,
+) -> Result<SigningShare<C>, Error<C>> {
+    // Use the same best-effort validation as `reconstruct`.
+    let min_signers = key_packages
+        .iter()
+        .map(|k| k.min_signers)
+        .min()
+        .ok_or(Error::IncorrectNumberOfShares)?;
+    can_reconstruct(key_packages, min_signers)?;
+
+    // Identifiers can't be zero, and `compute_lagrange_coefficient` uses None
+    // for it.
+    let x = if point == <<C::Group as Group>::Field>::zero() {
+        None
+    } else {
+        Some(Identifier::new(point)?)
+    };
+
+    let identifiers: BTreeSet<_> = key_packages.iter().map(|k| k.identifier).collect();
+
+    let mut value = <<C::Group as Group>::Field>::zero();
+    for key_package in key_packages {
+        let lagrange_coefficient =
+            compute_lagrange_coefficient(&identifiers, x, key_package.identifier)?;
+        value = value + (lagrange_coefficient * key_package.signing_share.0);
+    }
+
+    Ok(SigningShare(value))
+}
+
+/// Collapse a threshold key back into a single [`SigningKey`], e.g. for a
+/// "break-glass" scenario where one party takes sole ownership of the key.
+///
+/// As a safety interlock, this requires the [`KeyPackage`]s of *all*
+/// participants listed in `pubkey_package` (not just `min_signers` of them),
+/// so that it can only be done with the consent of every participant. The
+/// reconstructed key is checked against the group verifying key.
+///
+/// # Security
+///
+/// This is irreversible in the sense that whoever learns the returned key can
+/// sign on their own, regardless of what happens to the shares; all
+/// participants must trust the party that performs the reconstruction and
+/// holds the result. The shares should be deleted afterwards.
+pub fn collapse_to_single<C: Ciphersuite>(
+    key_packages: &[KeyPackage<C>],
+    pubkey_package: &PublicKeyPackage<C>,
+) -> Result<SigningKey<C>, Error<C>> {
+    if key_packages.len() != pubkey_package.verifying_shares.len() {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+    if key_packages
+        .iter()
+        .any(|k| !pubkey_package.verifying_shares.contains_key(&k.identifier))
+    {
+        return Err(Error::UnknownIdentifier);
+    }
+
+    let signing_key = reconstruct(key_packages)?;
+
+    if VerifyingKey::from(&signing_key) != pubkey_package.verifying_key {
+        return Err(Error::InvalidSecretShare);
+    }
+
+    Ok(signing_key)
+}
@@ -48,7 +48,7 @@ use super::{
 /// DKG Round 1 structures.
 pub mod round1 {
     use derive_getters::Getters;
-    use zeroize::Zeroize;
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
     #[cfg(feature = "serialization")]
     use crate::serialization::{Deserialize, Serialize};
@@ -107,15 +107,33 @@ pub mod round1 {
     /// The secret package that must be kept in memory by the participant
     /// between the first and second parts of the DKG protocol (round 1).
     ///
+    /// It can be serialized in order to persist the DKG state between rounds,
+    /// e.g. across restarts.
+    ///
     /// # Security
     ///
-    /// This package MUST NOT be sent to other participants!
+    /// This package MUST NOT be sent to other participants! If persisted, it
+    /// MUST be encrypted at rest, since it allows recovering the participant's
+    /// share of the group key. Its secrets are zeroized when it is dropped, and
+    /// so are the bytes returned by `serialize()`.
     #[derive(Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
     pub struct SecretPackage<C: Ciphersuite> {
+        /// Serialization header
+        pub(crate) header: Header<C>,
         /// The identifier of the participant holding the secret.
         pub(crate) identifier: Identifier<C>,
         /// Coefficients of the temporary secret polynomial for the participant.
         /// These are (a_{i0}, ..., a_{i(t−1)})) which define the polynomial f_i(x)
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                serialize_with = "crate::serialization::scalar_vec::serialize::<C, _>",
+                deserialize_with = "crate::serialization::scalar_vec::deserialize::<C, _>"
+            )
+        )]
         pub(crate) coefficients: Vec<Scalar<C>>,
         /// The public commitment for the participant (C_i)
         pub(crate) commitment: VerifiableSecretSharingCommitment<C>,
@@ -136,6 +154,22 @@ pub mod round1 {
         }
     }
 
+    #[cfg(feature = "serialization")]
+    impl<C> SecretPackage<C>
+    where
+        C: Ciphersuite,
+    {
+        /// Serialize the struct into a Vec, which is zeroized when dropped.
+        pub fn serialize(&self) -> Result<Zeroizing<Vec<u8>>, Error<C>> {
+            Serialize::serialize(&self).map(Zeroizing::new)
+        }
+
+        /// Deserialize the struct from a slice of bytes.
+        pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+            Deserialize::deserialize(bytes)
+        }
+    }
+
     impl<C> std::fmt::Debug for SecretPackage<C>
     where
         C: Ciphersuite,
@@ -161,12 +195,23 @@ pub mod round1 {
             }
         }
     }
+
+    impl<C> Drop for SecretPackage<C>
+    where
+        C: Ciphersuite,
+    {
+        fn drop(&mut self) {
+            self.zeroize();
+        }
+    }
+
+    impl<C> ZeroizeOnDrop for SecretPackage<C> where C: Ciphersuite {}
 }
 
 /// DKG Round 2 structures.
 pub mod round2 {
     use derive_getters::Getters;
-    use zeroize::Zeroize;
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
     #[cfg(feature = "serialization")]
     use crate::serialization::{Deserialize, Serialize};
@@ -224,16 +269,34 @@ pub mod round2 {
     /// The secret package that must be kept in memory by the participant
     /// between the second and third parts of the DKG protocol (round 2).
     ///
+    /// It can be serialized in order to persist the DKG state between rounds,
+    /// e.g. across restarts.
+    ///
     /// # Security
     ///
-    /// This package MUST NOT be sent to other participants!
+    /// This package MUST NOT be sent to other participants! If persisted, it
+    /// MUST be encrypted at rest, since it allows recovering the participant's
+    /// share of the group key. Its secrets are zeroized when it is dropped, and
+    /// so are the bytes returned by `serialize()`.
     #[derive(Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
     pub struct SecretPackage<C: Ciphersuite> {
+        /// Serialization header
+        pub(crate) header: Header<C>,
         /// The identifier of the participant holding the secret.
         pub(crate) identifier: Identifier<C>,
         /// The public commitment from the participant (C_i)
         pub(crate) commitment: VerifiableSecretSharingCommitment<C>,
         /// The participant's own secret share (f_i(i)).
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                serialize_with = "crate::serialization::scalar::serialize::<C, _>",
+                deserialize_with = "crate::serialization::scalar::deserialize::<C, _>"
+            )
+        )]
         pub(crate) secret_share: Scalar<C>,
         /// The minimum number of signers.
         pub(crate) min_signers: u16,
@@ -241,6 +304,22 @@ pub mod round2 {
         pub(crate) max_signers: u16,
     }
 
+    #[cfg(feature = "serialization")]
+    impl<C> SecretPackage<C>
+    where
+        C: Ciphersuite,
+    {
+        /// Serialize the struct into a Vec, which is zeroized when dropped.
+        pub fn serialize(&self) -> Result<Zeroizing<Vec<u8>>, Error<C>> {
+            Serialize::serialize(&self).map(Zeroizing::new)
+        }
+
+        /// Deserialize the struct from a slice of bytes.
+        pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+            Deserialize::deserialize(bytes)
+        }
+    }
+
     impl<C> std::fmt::Debug for SecretPackage<C>
     where
         C: Ciphersuite,
@@ -264,6 +343,17 @@ pub mod round2 {
             self.secret_share = <<C::Group as Group>::Field>::zero();
         }
     }
+
+    impl<C> Drop for SecretPackage<C>
+    where
+        C: Ciphersuite,
+    {
+        fn drop(&mut self) {
+            self.zeroize();
+        }
+    }
+
+    impl<C> ZeroizeOnDrop for SecretPackage<C> where C: Ciphersuite {}
 }
 
 /// Performs the first part of the distributed key generation protocol
@@ -276,11 +366,11 @@ pub fn part1<C: Ciphersuite, R: RngCore + CryptoRng>(
     identifier: Identifier<C>,
     max_signers: u16,
     min_signers: u16,
-    mut rng: R,
+    rng: &mut R,
 ) -> Result<(round1::SecretPackage<C>, round1::Package<C>), Error<C>> {
     validate_num_of_signers::<C>(min_signers, max_signers)?;
 
-    let secret: SigningKey<C> = SigningKey::new(&mut rng);
+    let secret: SigningKey<C> = SigningKey::new(rng);
 
     // Round 1, Step 1
     //
@@ -290,14 +380,15 @@ pub fn part1<C: Ciphersuite, R: RngCore + CryptoRng>(
     //
     // > Every participant P_i computes a public commitment
     // > C⃗_i = 〈φ_{i0}, ..., φ_{i(t−1)}〉, where φ_{ij} = g^{a_{ij}}, 0 ≤ j ≤ t − 1
-    let coefficients = generate_coefficients::<C, R>(min_signers as usize - 1, &mut rng);
+    let coefficients = generate_coefficients::<C, R>(min_signers as usize - 1, rng);
 
     let (coefficients, commitment) =
         generate_secret_polynomial(&secret, max_signers, min_signers, coefficients)?;
     let proof_of_knowledge =
-        compute_proof_of_knowledge(identifier, &coefficients, &commitment, &mut rng)?;
+        compute_proof_of_knowledge(identifier, &coefficients, &commitment, rng)?;
 
     let secret_package = round1::SecretPackage {
+        header: Header::default(),
         identifier,
         coefficients,
         commitment: commitment.clone(),
@@ -338,7 +429,7 @@ pub(crate) fn compute_proof_of_knowledge<C: Ciphersuite, R: RngCore + CryptoRng>
     identifier: Identifier<C>,
     coefficients: &[Scalar<C>],
     commitment: &VerifiableSecretSharingCommitment<C>,
-    mut rng: R,
+    rng: &mut R,
 ) -> Result<Signature<C>, Error<C>> {
     // Round 1, Step 2
     //
@@ -346,7 +437,7 @@ pub(crate) fn compute_proof_of_knowledge<C: Ciphersuite, R: RngCore + CryptoRng>
     // > a_{i0} by calculating σ_i = (R_i, μ_i), such that k ← Z_q, R_i = g^k,
     // > c_i = H(i, Φ, g^{a_{i0}} , R_i), μ_i = k + a_{i0} · c_i, with Φ being
     // > a context string to prevent replay attacks.
-    let k = <<C::Group as Group>::Field>::random(&mut rng);
+    let k = <<C::Group as Group>::Field>::random(rng);
     let R_i = <C::Group>::generator() * k;
     let c_i = challenge::<C>(identifier, &commitment.verifying_key()?, &R_i)
         .ok_or(Error::DKGNotSupported)?;
@@ -409,7 +500,7 @@ pub fn part2<C: Ciphersuite>(
     }
 
     for package in round1_packages.values() {
-        if package.commitment.0.len() != secret_package.min_signers as usize {
+        if package.commitment.coefficients().len() != secret_package.min_signers as usize {
             return Err(Error::IncorrectNumberOfCommitments);
         }
     }
@@ -443,8 +534,9 @@ pub fn part2<C: Ciphersuite>(
     let fii = evaluate_polynomial(secret_package.identifier, &secret_package.coefficients);
     Ok((
         round2::SecretPackage {
+            header: Header::default(),
             identifier: secret_package.identifier,
-            commitment: secret_package.commitment,
+            commitment: secret_package.commitment.clone(),
             secret_share: fii,
             min_signers: secret_package.min_signers,
             max_signers: secret_package.max_signers,
@@ -549,6 +641,7 @@ pub fn part3<C: Ciphersuite>(
         verifying_share,
         verifying_key: public_key_package.verifying_key,
         min_signers: round2_secret_package.min_signers,
+        capabilities: Default::default(),
     };
 
     Ok((key_package, public_key_package))
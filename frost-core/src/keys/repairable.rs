@@ -7,11 +7,14 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::{
-    compute_lagrange_coefficient, Ciphersuite, CryptoRng, Error, Field, Group, Header, Identifier,
-    RngCore, Scalar,
+    compute_lagrange_coefficient, Ciphersuite, CryptoRng, Element, Error, Field, Group, Header,
+    Identifier, RngCore, Scalar,
 };
 
-use super::{generate_coefficients, SecretShare, SigningShare, VerifiableSecretSharingCommitment};
+use super::{
+    generate_coefficients, PublicKeyPackage, SecretShare, SigningShare,
+    VerifiableSecretSharingCommitment,
+};
 
 /// Step 1 of RTS.
 ///
@@ -78,6 +81,22 @@ fn compute_last_random_value<C: Ciphersuite>(
     Ok(out)
 }
 
+/// Commit to the "delta" values generated by `helper_i` in step 1.
+///
+/// The commitments are sent to the `participant` (and can be sent to the other
+/// helpers) so that [`repair_share_robust`] can identify a helper that
+/// misbehaved.
+///
+/// Returns a BTreeMap mapping each helper to the commitment to the delta sent to them.
+pub fn repair_share_delta_commitments<C: Ciphersuite>(
+    deltas_i: &BTreeMap<Identifier<C>, Scalar<C>>,
+) -> BTreeMap<Identifier<C>, Element<C>> {
+    deltas_i
+        .iter()
+        .map(|(helper, delta)| (*helper, <C::Group>::generator() * *delta))
+        .collect()
+}
+
 /// Communication round
 ///
 /// `helper_i` sends 1 `delta_j` to all other helpers (j)
@@ -90,6 +109,7 @@ fn compute_last_random_value<C: Ciphersuite>(
 /// `sigma` is the sum of all received `delta` and the `delta_i` generated for `helper_i`.
 ///
 /// Returns a scalar
+#[allow(clippy::empty_line_after_doc_comments)]
 pub fn repair_share_step_2<C: Ciphersuite>(deltas_j: &[Scalar<C>]) -> Scalar<C> {
     let mut sigma_j = <<C::Group as Group>::Field>::zero();
 
@@ -109,6 +129,7 @@ pub fn repair_share_step_2<C: Ciphersuite>(deltas_j: &[Scalar<C>]) -> Scalar<C>
 /// The `participant` sums all `sigma_j` received to compute the `share`. The `SecretShare`
 /// is made up of the `identifier`and `commitment` of the `participant` as well as the
 /// `value` which is the `SigningShare`.
+#[allow(clippy::empty_line_after_doc_comments)]
 pub fn repair_share_step_3<C: Ciphersuite>(
     sigmas: &[Scalar<C>],
     identifier: Identifier<C>,
@@ -127,3 +148,63 @@ pub fn repair_share_step_3<C: Ciphersuite>(
         commitment: commitment.clone(),
     }
 }
+
+/// Step 3 of RTS, checking the contributions of the helpers.
+///
+/// Like [`repair_share_step_3`], but `sigmas` maps each helper to the `sigma_j`
+/// they sent, and `delta_commitments` maps each helper to the commitments to
+/// their deltas returned by [`repair_share_delta_commitments`]. Each helper's
+/// delta commitments are checked against their verifying share in `pubkeys`,
+/// and each `sigma_j` against the commitments to the deltas it sums.
+///
+/// Returns the repaired `SecretShare`, or [`Error::InvalidParticipantSecretShare`] naming
+/// the first helper found to have sent a wrong contribution. Note that a
+/// helper which sends another helper a delta that does not match its
+/// commitment will cause the latter to be named; helpers can avoid this by
+/// checking the deltas they receive against the commitments.
+pub fn repair_share_robust<C: Ciphersuite>(
+    sigmas: &BTreeMap<Identifier<C>, Scalar<C>>,
+    delta_commitments: &BTreeMap<Identifier<C>, BTreeMap<Identifier<C>, Element<C>>>,
+    pubkeys: &PublicKeyPackage<C>,
+    identifier: Identifier<C>,
+    commitment: &VerifiableSecretSharingCommitment<C>,
+) -> Result<SecretShare<C>, Error<C>> {
+    let helpers: BTreeSet<_> = sigmas.keys().copied().collect();
+    if helpers.len() < 2 {
+        return Err(Error::InvalidMinSigners);
+    }
+    if !delta_commitments.keys().eq(helpers.iter()) {
+        return Err(Error::IncorrectNumberOfCommitments);
+    }
+
+    // Each helper's deltas must add up to `zeta_i * share_i`.
+    for (helper_i, commitments_i) in delta_commitments {
+        let verifying_share = pubkeys
+            .verifying_shares
+            .get(helper_i)
+            .ok_or(Error::UnknownIdentifier)?;
+        let zeta_i = compute_lagrange_coefficient(&helpers, Some(identifier), *helper_i)?;
+        let sum = commitments_i
+            .values()
+            .fold(<C::Group>::identity(), |acc, c| acc + *c);
+        if !commitments_i.keys().eq(helpers.iter()) || sum != verifying_share.0 * zeta_i {
+            return Err(Error::InvalidParticipantSecretShare { culprit: *helper_i });
+        }
+    }
+
+    // Each sigma must be the sum of the deltas sent to that helper.
+    for (helper_j, sigma_j) in sigmas {
+        let sum = delta_commitments
+            .values()
+            .filter_map(|commitments_i| commitments_i.get(helper_j))
+            .fold(<C::Group>::identity(), |acc, c| acc + *c);
+        if <C::Group>::generator() * *sigma_j != sum {
+            return Err(Error::InvalidParticipantSecretShare { culprit: *helper_j });
+        }
+    }
+
+    let sigmas: Vec<_> = sigmas.values().copied().collect();
+    let secret_share = repair_share_step_3(&sigmas, identifier, commitment);
+    secret_share.verify()?;
+    Ok(secret_share)
+}
@@ -15,6 +15,7 @@ use std::{
     default::Default,
     fmt::{self, Debug},
     marker::PhantomData,
+    time::{Duration, Instant},
 };
 
 use derive_getters::Getters;
@@ -26,9 +27,15 @@ use zeroize::Zeroize;
 pub mod batch;
 #[cfg(any(test, feature = "test-impl"))]
 pub mod benches;
+pub mod chained;
+#[cfg(any(test, feature = "test-impl"))]
+pub mod conformance;
 mod error;
 mod identifier;
+#[cfg(feature = "json-debug")]
+mod json_debug;
 pub mod keys;
+pub mod multi_group;
 pub mod round1;
 pub mod round2;
 mod scalar_mul;
@@ -38,18 +45,19 @@ mod scalar_mul;
 pub mod serialization;
 mod signature;
 mod signing_key;
+pub mod simple;
 #[cfg(any(test, feature = "test-impl"))]
 pub mod tests;
 mod traits;
 mod verifying_key;
 
-pub use error::{Error, FieldError, GroupError};
+pub use error::{Error, FieldError, GroupError, ERROR_CODE_VERSION};
 pub use identifier::Identifier;
 use scalar_mul::VartimeMultiscalarMul;
 // Re-export serde
 #[cfg(feature = "serde")]
 pub use serde;
-pub use signature::Signature;
+pub use signature::{Signature, SIGNATURE_FRAME_TAG};
 pub use signing_key::SigningKey;
 pub use traits::{Ciphersuite, Element, Field, Group, Scalar};
 pub use verifying_key::VerifyingKey;
@@ -177,14 +185,18 @@ where
 ///
 /// <https://github.com/cfrg/draft-irtf-cfrg-frost/blob/master/draft-irtf-cfrg-frost.md>
 #[derive(Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "internals", visibility::make(pub))]
-#[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
-pub(crate) struct BindingFactor<C: Ciphersuite>(Scalar<C>);
+pub struct BindingFactor<C: Ciphersuite>(Scalar<C>);
 
 impl<C> BindingFactor<C>
 where
     C: Ciphersuite,
 {
+    /// Create a new [`BindingFactor`] from a scalar, e.g. computed by hashing
+    /// one of the [`SigningPackage::binding_factor_preimages`] with `H1`.
+    pub fn new(scalar: Scalar<C>) -> Self {
+        Self(scalar)
+    }
+
     /// Serializes [`BindingFactor`] to bytes.
     pub fn serialize(&self) -> <<C::Group as Group>::Field as Field>::Serialization {
         <<C::Group as Group>::Field>::serialize(&self.0)
@@ -344,6 +356,11 @@ fn derive_interpolating_value<C: Ciphersuite>(
 
 /// Generated by the coordinator of the signing operation and distributed to
 /// each signing party
+///
+/// The signing commitments are kept in a [`BTreeMap`] keyed by identifier, so
+/// equality (and serialization) does not depend on the order in which the
+/// commitments were inserted. Signers can thus compare the packages they
+/// received with `==` to check they all got the same one.
 #[derive(Clone, Debug, PartialEq, Eq, Getters)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
@@ -354,6 +371,10 @@ pub struct SigningPackage<C: Ciphersuite> {
     pub(crate) header: Header<C>,
     /// The set of commitments participants published in the first round of the
     /// protocol.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::signing_commitments::deserialize")
+    )]
     signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
     /// Message which each participant will sign.
     ///
@@ -363,7 +384,7 @@ pub struct SigningPackage<C: Ciphersuite> {
         feature = "serde",
         serde(
             serialize_with = "serdect::slice::serialize_hex_lower_or_bin",
-            deserialize_with = "serdect::slice::deserialize_hex_or_bin_vec"
+            deserialize_with = "crate::serialization::message::deserialize"
         )
     )]
     message: Vec<u8>,
@@ -376,6 +397,16 @@ where
     /// Create a new `SigningPackage`
     ///
     /// The `signing_commitments` are sorted by participant `identifier`.
+    ///
+    /// This constructor is infallible, so it doesn't bound the number of
+    /// signing commitments or the message length: the coordinator that calls
+    /// it already holds them, and [`aggregate`] rejects signing sets larger
+    /// than [`DEFAULT_SIGNER_LIMIT`] while [`round2::sign`] rejects messages
+    /// longer than [`DEFAULT_MESSAGE_LIMIT`], as does deserializing a
+    /// `SigningPackage` received from a peer. Use
+    /// [`SigningPackage::new_with_signer_limit`] and
+    /// [`SigningPackage::new_with_message_limit`] to reject them before the
+    /// signing operation starts.
     pub fn new(
         signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
         message: &[u8],
@@ -387,6 +418,84 @@ where
         }
     }
 
+    /// Create a new `SigningPackage`, returning [`Error::TooManySigners`] if
+    /// there are more than `signer_limit` signing commitments.
+    ///
+    /// This can be used by a coordinator to bound the resources spent on a
+    /// signing operation; see also [`DEFAULT_SIGNER_LIMIT`].
+    pub fn new_with_signer_limit(
+        signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
+        message: &[u8],
+        signer_limit: usize,
+    ) -> Result<SigningPackage<C>, Error<C>> {
+        if signing_commitments.len() > signer_limit {
+            return Err(Error::TooManySigners);
+        }
+
+        Ok(Self::new(signing_commitments, message))
+    }
+
+    /// Create a new `SigningPackage`, returning [`Error::MessageTooLong`] if
+    /// the message is longer than `message_limit` bytes.
+    ///
+    /// Signers enforce [`DEFAULT_MESSAGE_LIMIT`] in [`round2::sign`], or
+    /// another limit with [`round2::sign_with_message_limit`].
+    pub fn new_with_message_limit(
+        signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
+        message: &[u8],
+        message_limit: usize,
+    ) -> Result<SigningPackage<C>, Error<C>> {
+        if message.len() > message_limit {
+            return Err(Error::MessageTooLong);
+        }
+
+        Ok(Self::new(signing_commitments, message))
+    }
+
+    /// Create a new `SigningPackage` whose signature is bound to the set of
+    /// signers, i.e. the identifiers of the `signing_commitments`.
+    ///
+    /// The package message is set to [`SigningPackage::bound_message`], so the
+    /// signers' identifiers are absorbed into both the binding factors and the
+    /// challenge, and the resulting signature only verifies with
+    /// [`VerifyingKey::verify_bound_to_set`] for the same set of signers.
+    ///
+    /// This is an extension to the FROST specification: the signature is a
+    /// regular Schnorr signature over the bound message, so verifiers that are
+    /// not aware of the signing set can't check it against the original
+    /// message.
+    pub fn new_bound_to_set(
+        signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
+        message: &[u8],
+    ) -> SigningPackage<C> {
+        let signers = signing_commitments.keys().copied().collect();
+        let message = Self::bound_message(&signers, message);
+        Self::new(signing_commitments, &message)
+    }
+
+    /// Return the message actually signed by a package created with
+    /// [`SigningPackage::new_bound_to_set`] for the given signers, which is
+    /// `H5(contextString || len(identifiers) || identifiers) || message`, where
+    /// `contextString` is the ciphersuite ID followed by `"bound-to-set"`,
+    /// `identifiers` are the sorted and serialized identifiers, and their
+    /// length in bytes is encoded as a big-endian `u64`.
+    pub fn bound_message(signers: &BTreeSet<Identifier<C>>, message: &[u8]) -> Vec<u8> {
+        let identifiers: Vec<u8> = signers
+            .iter()
+            .flat_map(|identifier| identifier.serialize().as_ref().to_vec())
+            .collect();
+
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(C::ID.as_bytes());
+        preimage.extend_from_slice(b"bound-to-set");
+        preimage.extend_from_slice(&(identifiers.len() as u64).to_be_bytes());
+        preimage.extend_from_slice(&identifiers);
+
+        let mut bound_message = C::H5(&preimage).as_ref().to_vec();
+        bound_message.extend_from_slice(message);
+        bound_message
+    }
+
     /// Get a signing commitment by its participant identifier, or None if not found.
     pub fn signing_commitment(
         &self,
@@ -395,6 +504,98 @@ where
         self.signing_commitments.get(identifier).copied()
     }
 
+    /// Return the identifiers in `expected` which have no signing commitment in
+    /// this package, e.g. the signers a coordinator is still waiting on.
+    pub fn missing_signers(&self, expected: &BTreeSet<Identifier<C>>) -> BTreeSet<Identifier<C>> {
+        expected
+            .iter()
+            .filter(|identifier| !self.signing_commitments.contains_key(identifier))
+            .copied()
+            .collect()
+    }
+
+    /// Check that every signing commitment in this package is the one its
+    /// signer published in `signed_commitments`, with a valid signature under
+    /// the signer's verifying share in `pubkeys`.
+    ///
+    /// This lets signers detect a coordinator substituting their commitments
+    /// (or other signers' commitments) with stale or attacker-chosen ones.
+    /// Returns [`Error::InvalidCommitmentSignature`] for the first signer whose
+    /// commitment is missing from `signed_commitments`, differs from it or is
+    /// not validly signed, and [`Error::UnknownIdentifier`] if a signer is not
+    /// in `pubkeys`.
+    pub fn verify_signed_commitments(
+        &self,
+        signed_commitments: &BTreeMap<Identifier<C>, round1::SignedCommitments<C>>,
+        pubkeys: &keys::PublicKeyPackage<C>,
+    ) -> Result<(), Error<C>> {
+        for (identifier, commitments) in &self.signing_commitments {
+            let verifying_share = pubkeys
+                .verifying_shares()
+                .get(identifier)
+                .ok_or(Error::UnknownIdentifier)?;
+            let culprit = Error::InvalidCommitmentSignature {
+                culprit: *identifier,
+            };
+            let signed = signed_commitments.get(identifier).ok_or(culprit)?;
+            if signed.commitments() != commitments {
+                return Err(culprit);
+            }
+            signed.verify(verifying_share).map_err(|_| culprit)?;
+        }
+        Ok(())
+    }
+
+    /// Check that the signing commitments in this package are well-formed.
+    ///
+    /// Returns [`Error::IdentityCommitment`] if a commitment is the identity,
+    /// [`Error::DegenerateCommitment`] if a signer's hiding and binding
+    /// commitments are equal (e.g. both derived from the same nonce), and
+    /// [`Error::CollidingCommitments`] if a commitment is shared between
+    /// signers (e.g. due to a shared faulty RNG).
+    ///
+    /// Signing, share verification and aggregation call this before using the
+    /// package (and, when signing, before using the signer's nonces), so it
+    /// only needs to be called to reject a package early, e.g. by the
+    /// coordinator that builds it.
+    pub fn validate(&self) -> Result<(), Error<C>> {
+        let identity = <C::Group as Group>::identity();
+
+        // The signer that published each (serialized) commitment seen so far.
+        let mut commitment_owners = BTreeMap::new();
+
+        for (identifier, commitment) in &self.signing_commitments {
+            if identity == commitment.binding.0 || identity == commitment.hiding.0 {
+                return Err(Error::IdentityCommitment);
+            }
+            if commitment.binding.0 == commitment.hiding.0 {
+                return Err(Error::DegenerateCommitment {
+                    culprit: *identifier,
+                });
+            }
+            for nonce_commitment in [commitment.hiding, commitment.binding] {
+                if let Some(first) = commitment_owners
+                    .insert(nonce_commitment.serialize().as_ref().to_vec(), *identifier)
+                {
+                    return Err(Error::CollidingCommitments {
+                        first,
+                        second: *identifier,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Compute the digest `H4(message)` of the message to be signed.
+    ///
+    /// Signers can exchange this digest (e.g. out of band) before signing and
+    /// pass the agreed value to [`round2::sign_with_message_digest`], so that a
+    /// coordinator can't get signers to sign different messages.
+    pub fn message_digest(&self) -> C::HashOutput {
+        C::H4(self.message.as_slice())
+    }
+
     /// Compute the preimages to H1 to compute the per-signer binding factors
     // We separate this out into its own method so it can be tested
     #[cfg_attr(feature = "internals", visibility::make(pub))]
@@ -520,10 +721,65 @@ where
     Ok(GroupCommitment(group_commitment))
 }
 
+/// Recompute the group commitment `R` for a signing operation from the
+/// signing commitments and message in the [`SigningPackage`].
+///
+/// This derives each signer's binding factor _rho_ and sums
+/// `hiding + binding * rho` over all signers; the result is the `R` that the
+/// final [`Signature`] must contain. This allows e.g. light clients to cheaply
+/// check that a signature belongs to a given signing operation before
+/// verifying it.
+pub fn aggregate_commitment<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    verifying_key: &VerifyingKey<C>,
+) -> Result<Element<C>, Error<C>> {
+    aggregate_commitment_internal(signing_package, verifying_key, &[])
+}
+
+/// Like [`aggregate_commitment`], for a signing operation whose shares are
+/// produced with [`round2::sign_with_session_id`] for the given `session_id`.
+pub fn aggregate_commitment_with_session_id<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    verifying_key: &VerifyingKey<C>,
+    session_id: &[u8; 32],
+) -> Result<Element<C>, Error<C>> {
+    aggregate_commitment_internal(signing_package, verifying_key, session_id)
+}
+
+fn aggregate_commitment_internal<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    verifying_key: &VerifyingKey<C>,
+    additional_prefix: &[u8],
+) -> Result<Element<C>, Error<C>> {
+    signing_package.validate()?;
+
+    let binding_factor_list: BindingFactorList<C> =
+        compute_binding_factor_list(signing_package, verifying_key, additional_prefix);
+    let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
+
+    Ok(group_commitment.0)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Aggregation
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The maximum message length, in bytes, accepted by [`round2::sign`] and
+/// when deserializing a [`SigningPackage`].
+///
+/// This protects signers from allocating and hashing an unbounded message sent
+/// by a peer. Use [`round2::sign_with_message_limit`] and
+/// [`SigningPackage::new_with_message_limit`] for a different limit.
+pub const DEFAULT_MESSAGE_LIMIT: usize = 16 * 1024 * 1024;
+
+/// The maximum number of signers accepted by [`aggregate`], and of signing
+/// commitments accepted when deserializing a [`SigningPackage`].
+///
+/// This protects coordinators and signers from allocating an enormous
+/// signing set sent by a peer. Use [`aggregate_with_signer_limit`] and
+/// [`SigningPackage::new_with_signer_limit`] for a different limit.
+pub const DEFAULT_SIGNER_LIMIT: usize = 10_000;
+
 /// Aggregates the signature shares to produce a final signature that
 /// can be verified with the group public key.
 ///
@@ -533,6 +789,12 @@ where
 /// they must have assurance that the [`round2::SignatureShare`] came from
 /// the participant with that identifier.
 ///
+/// The resulting signature's `R` is the group commitment computed from the
+/// [`SigningPackage`], and its `z` is the plain sum (modulo the group order) of
+/// the `z_share` scalars of the signature shares, so that the aggregate can be
+/// reconstructed from the [serialized](round2::SignatureShare::serialize)
+/// shares by other implementations.
+///
 /// This operation is performed by a coordinator that can communicate with all
 /// the signing participants before publishing the final signature. The
 /// coordinator can be one of the participants or a semi-trusted third party
@@ -542,7 +804,27 @@ where
 /// signature, if the coordinator themselves is a signer and misbehaves, they
 /// can avoid that step. However, at worst, this results in a denial of
 /// service attack due to publishing an invalid signature.
-
+///
+/// Individual signature shares are only verified if the aggregate signature
+/// fails to verify against the group verifying key, in order to identify the
+/// misbehaving participant (with the `cheater-detection` feature). When all
+/// shares are valid, which is the common case, aggregation costs a single
+/// signature verification, so there is no need to skip share verification
+/// even if shares were already verified upstream.
+///
+/// Returns [`Error::InsufficientShares`] if there are fewer signature shares
+/// than the minimum number of signers recorded in `pubkeys` (see
+/// [`PublicKeyPackage::min_signers`](keys::PublicKeyPackage::min_signers)),
+/// [`Error::TooManySigners`] if the signing package or the signature shares
+/// contain more than [`DEFAULT_SIGNER_LIMIT`] signers, and
+/// [`Error::UnexpectedSigner`] if a signature share is from a signer without a
+/// commitment in the [`SigningPackage`].
+///
+/// If `pubkeys` doesn't record the minimum number of signers, e.g. because it
+/// was created with [`PublicKeyPackage::new`](keys::PublicKeyPackage::new) or
+/// deserialized from format version 0, the number of shares can't be checked
+/// upfront: aggregating too few shares then fails when verifying the
+/// resulting signature, with [`Error::InvalidSignature`].
 pub fn aggregate<C>(
     signing_package: &SigningPackage<C>,
     signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
@@ -551,6 +833,318 @@ pub fn aggregate<C>(
 where
     C: Ciphersuite,
 {
+    aggregate_internal(
+        signing_package,
+        signature_shares,
+        pubkeys,
+        DEFAULT_SIGNER_LIMIT,
+        None,
+        &[],
+        None,
+    )
+    .map(|(signature, _)| signature)
+}
+
+/// Like [`aggregate`], but returns [`Error::TooManySigners`] if the signing
+/// package or the signature shares contain more than `signer_limit` signers,
+/// instead of [`DEFAULT_SIGNER_LIMIT`].
+pub fn aggregate_with_signer_limit<C>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    signer_limit: usize,
+) -> Result<Signature<C>, Error<C>>
+where
+    C: Ciphersuite,
+{
+    aggregate_internal(
+        signing_package,
+        signature_shares,
+        pubkeys,
+        signer_limit,
+        None,
+        &[],
+        None,
+    )
+    .map(|(signature, _)| signature)
+}
+
+/// The public intermediate values derived while aggregating a signature,
+/// returned by [`aggregate_with_details`] e.g. for audit logs.
+#[derive(Clone, PartialEq, Eq, Getters)]
+pub struct AggregateDetails<C: Ciphersuite> {
+    /// The group commitment `R`, which is also the `R` of the signature.
+    group_commitment: Element<C>,
+    /// The binding factor _rho_ of each signer.
+    binding_factors: BTreeMap<Identifier<C>, BindingFactor<C>>,
+    /// The challenge `c` computed from `R`, the group verifying key and the
+    /// message.
+    challenge: Scalar<C>,
+}
+
+impl<C> Debug for AggregateDetails<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AggregateDetails")
+            .field(
+                "group_commitment",
+                &hex::encode(<C::Group>::serialize(&self.group_commitment)),
+            )
+            .field("binding_factors", &self.binding_factors)
+            .field(
+                "challenge",
+                &hex::encode(<<C::Group as Group>::Field>::serialize(&self.challenge)),
+            )
+            .finish()
+    }
+}
+
+/// Like [`aggregate`], but uses the given `challenge` instead of deriving it
+/// from the group commitment, the group verifying key and the message, for
+/// signature shares produced with [`round2::sign_with_challenge`].
+///
+/// The signature is checked against the given challenge, so it will generally
+/// not verify with [`VerifyingKey::verify`].
+///
+/// # Security
+///
+/// The caller is responsible for the soundness of the challenge; see
+/// [`round2::sign_with_challenge`].
+pub fn aggregate_with_challenge<C>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    challenge: Scalar<C>,
+) -> Result<Signature<C>, Error<C>>
+where
+    C: Ciphersuite,
+{
+    aggregate_internal(
+        signing_package,
+        signature_shares,
+        pubkeys,
+        DEFAULT_SIGNER_LIMIT,
+        Some(Challenge(challenge)),
+        &[],
+        None,
+    )
+    .map(|(signature, _)| signature)
+}
+
+/// Like [`aggregate`], for signature shares produced with
+/// [`round2::sign_with_session_id`] for the given `session_id`.
+///
+/// Shares produced for a different session (or with [`round2::sign`]) don't
+/// aggregate to a valid signature.
+pub fn aggregate_with_session_id<C>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    session_id: &[u8; 32],
+) -> Result<Signature<C>, Error<C>>
+where
+    C: Ciphersuite,
+{
+    aggregate_internal(
+        signing_package,
+        signature_shares,
+        pubkeys,
+        DEFAULT_SIGNER_LIMIT,
+        None,
+        session_id,
+        None,
+    )
+    .map(|(signature, _)| signature)
+}
+
+/// Like [`aggregate`], but reports how long each phase of the aggregation
+/// took by calling `on_metric` with the name of the phase and its duration,
+/// e.g. to export them to a monitoring system.
+///
+/// The phases, in order, are:
+///
+/// - `"group_commitment"`: computing the binding factors and the group
+///   commitment;
+/// - `"summation"`: summing the signature shares;
+/// - `"verification"`: verifying the aggregate signature;
+/// - `"share_verification"`: verifying each signature share to find the
+///   misbehaving participant; this is only reported if the aggregate signature
+///   is invalid (with the `cheater-detection` feature).
+///
+/// Phases are not reported if aggregation fails before reaching them.
+///
+/// Durations are measured with [`std::time::Instant`], which panics on
+/// platforms without a clock such as `wasm32-unknown-unknown`; the other
+/// aggregation functions never read the clock.
+pub fn aggregate_instrumented<C, F>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    mut on_metric: F,
+) -> Result<Signature<C>, Error<C>>
+where
+    C: Ciphersuite,
+    F: FnMut(&str, Duration),
+{
+    aggregate_internal(
+        signing_package,
+        signature_shares,
+        pubkeys,
+        DEFAULT_SIGNER_LIMIT,
+        None,
+        &[],
+        Some(&mut on_metric),
+    )
+    .map(|(signature, _)| signature)
+}
+
+/// Like [`aggregate`], but also returns the [`AggregateDetails`] derived
+/// while aggregating, so that they don't need to be recomputed.
+pub fn aggregate_with_details<C>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<(Signature<C>, AggregateDetails<C>), Error<C>>
+where
+    C: Ciphersuite,
+{
+    aggregate_internal(
+        signing_package,
+        signature_shares,
+        pubkeys,
+        DEFAULT_SIGNER_LIMIT,
+        None,
+        &[],
+        None,
+    )
+}
+
+/// Collects signature shares as they arrive at the coordinator, so that
+/// progress can be reported, and aggregates them with [`aggregate`] once all
+/// the signers in the [`SigningPackage`] have sent theirs.
+#[derive(Clone, Debug)]
+pub struct Aggregator<C: Ciphersuite> {
+    signing_package: SigningPackage<C>,
+    pubkeys: keys::PublicKeyPackage<C>,
+    signature_shares: BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+}
+
+impl<C> Aggregator<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`Aggregator`] for the given signing operation.
+    pub fn new(signing_package: SigningPackage<C>, pubkeys: keys::PublicKeyPackage<C>) -> Self {
+        Self {
+            signing_package,
+            pubkeys,
+            signature_shares: BTreeMap::new(),
+        }
+    }
+
+    /// Add the signature share sent by the signer with the given identifier.
+    ///
+    /// Returns [`Error::UnexpectedSigner`] if the signer has no commitment in
+    /// the signing package, and [`Error::DuplicatedShares`] if a share was
+    /// already added for them.
+    pub fn add(
+        &mut self,
+        identifier: Identifier<C>,
+        signature_share: round2::SignatureShare<C>,
+    ) -> Result<(), Error<C>> {
+        if !self
+            .signing_package
+            .signing_commitments()
+            .contains_key(&identifier)
+        {
+            return Err(Error::UnexpectedSigner {
+                culprit: identifier,
+            });
+        }
+        if self.signature_shares.contains_key(&identifier) {
+            return Err(Error::DuplicatedShares);
+        }
+        self.signature_shares.insert(identifier, signature_share);
+        Ok(())
+    }
+
+    /// Return the number of signature shares collected so far.
+    pub fn collected(&self) -> usize {
+        self.signature_shares.len()
+    }
+
+    /// Return the number of signature shares still needed to aggregate.
+    ///
+    /// Every signer in the signing package (of which there are at least
+    /// `min_signers`) must send a share, so this counts the signers that
+    /// have not sent theirs yet.
+    pub fn remaining(&self) -> usize {
+        self.signing_package
+            .signing_commitments()
+            .len()
+            .saturating_sub(self.collected())
+    }
+
+    /// Return true if all the signature shares needed to aggregate were
+    /// collected.
+    pub fn is_ready(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Aggregate the collected signature shares with [`aggregate`].
+    ///
+    /// Returns [`Error::IncorrectNumberOfShares`] if the aggregator is not
+    /// [ready](Self::is_ready) yet.
+    pub fn aggregate(&self) -> Result<Signature<C>, Error<C>> {
+        if !self.is_ready() {
+            return Err(Error::IncorrectNumberOfShares);
+        }
+        aggregate(&self.signing_package, &self.signature_shares, &self.pubkeys)
+    }
+}
+
+/// A callback receiving the name and duration of each aggregation phase; see
+/// [`aggregate_instrumented`].
+type MetricSink<'a> = &'a mut dyn FnMut(&str, Duration);
+
+fn aggregate_internal<C>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    signer_limit: usize,
+    challenge: Option<Challenge<C>>,
+    additional_prefix: &[u8],
+    mut on_metric: Option<MetricSink<'_>>,
+) -> Result<(Signature<C>, AggregateDetails<C>), Error<C>>
+where
+    C: Ciphersuite,
+{
+    // Fewer than `min_signers` shares can't produce a valid signature, so
+    // fail early rather than after computing an invalid one.
+    if let Some(min_signers) = pubkeys.min_signers {
+        if signature_shares.len() < min_signers as usize {
+            return Err(Error::InsufficientShares);
+        }
+    }
+
+    if signing_package.signing_commitments().len() > signer_limit
+        || signature_shares.len() > signer_limit
+    {
+        return Err(Error::TooManySigners);
+    }
+
+    // Reject shares from signers that did not commit in round one.
+    if let Some(identifier) = signature_shares
+        .keys()
+        .find(|id| !signing_package.signing_commitments().contains_key(id))
+    {
+        return Err(Error::UnexpectedSigner {
+            culprit: *identifier,
+        });
+    }
+
     // Check if signing_package.signing_commitments and signature_shares have
     // the same set of identifiers, and if they are all in pubkeys.verifying_shares.
     if signing_package.signing_commitments().len() != signature_shares.len() {
@@ -565,14 +1159,25 @@ where
         return Err(Error::UnknownIdentifier);
     }
 
+    signing_package.validate()?;
+
+    // Only read the clock if the caller asked for metrics.
+    let timing = on_metric.is_some();
+    let now = || timing.then(Instant::now);
+
+    let start = now();
+
     // Encodes the signing commitment list produced in round one as part of generating [`BindingFactor`], the
     // binding factor.
     let binding_factor_list: BindingFactorList<C> =
-        compute_binding_factor_list(signing_package, &pubkeys.verifying_key, &[]);
+        compute_binding_factor_list(signing_package, &pubkeys.verifying_key, additional_prefix);
 
     // Compute the group commitment from signing commitments produced in round one.
     let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
 
+    report_phase(&mut on_metric, "group_commitment", start);
+    let start = now();
+
     // The aggregation of the signature shares by summing them up, resulting in
     // a plain Schnorr signature.
     //
@@ -590,54 +1195,47 @@ where
         z,
     };
 
-    // Verify the aggregate signature
-    let verification_result = pubkeys
-        .verifying_key
-        .verify(signing_package.message(), &signature);
+    report_phase(&mut on_metric, "summation", start);
+    let start = now();
+
+    // Verify the aggregate signature, with the supplied challenge if any, or
+    // else with the per-message challenge.
+    let (challenge, verification_result) = match challenge {
+        Some(challenge) => {
+            let verification_result = pubkeys
+                .verifying_key
+                .verify_prehashed(challenge.clone(), &signature);
+            (challenge, verification_result)
+        }
+        None => (
+            crate::challenge::<C>(
+                &group_commitment.0,
+                &pubkeys.verifying_key,
+                signing_package.message().as_slice(),
+            ),
+            pubkeys
+                .verifying_key
+                .verify(signing_package.message(), &signature),
+        ),
+    };
+
+    report_phase(&mut on_metric, "verification", start);
 
     // Only if the verification of the aggregate signature failed; verify each share to find the cheater.
     // This approach is more efficient since we don't need to verify all shares
     // if the aggregate signature is valid (which should be the common case).
     #[cfg(feature = "cheater-detection")]
     if let Err(err) = verification_result {
-        // Compute the per-message challenge.
-        let challenge = crate::challenge::<C>(
-            &group_commitment.0,
-            &pubkeys.verifying_key,
-            signing_package.message().as_slice(),
+        let start = now();
+        let share_verification_result = verify_signature_shares(
+            signing_package,
+            signature_shares,
+            pubkeys,
+            &binding_factor_list,
+            &challenge,
         );
-
-        // Verify the signature shares.
-        for (signature_share_identifier, signature_share) in signature_shares {
-            // Look up the public key for this signer, where `signer_pubkey` = _G.ScalarBaseMult(s[i])_,
-            // and where s[i] is a secret share of the constant term of _f_, the secret polynomial.
-            let signer_pubkey = pubkeys
-                .verifying_shares
-                .get(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?;
-
-            // Compute Lagrange coefficient.
-            let lambda_i = derive_interpolating_value(signature_share_identifier, signing_package)?;
-
-            let binding_factor = binding_factor_list
-                .get(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?;
-
-            // Compute the commitment share.
-            let R_share = signing_package
-                .signing_commitment(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?
-                .to_group_commitment_share(binding_factor);
-
-            // Compute relation values to verify this signature share.
-            signature_share.verify(
-                *signature_share_identifier,
-                &R_share,
-                signer_pubkey,
-                lambda_i,
-                &challenge,
-            )?;
-        }
+        report_phase(&mut on_metric, "share_verification", start);
+        share_verification_result?;
 
         // We should never reach here; but we return the verification error to be safe.
         return Err(err);
@@ -646,5 +1244,65 @@ where
     #[cfg(not(feature = "cheater-detection"))]
     verification_result?;
 
-    Ok(signature)
+    let details = AggregateDetails {
+        group_commitment: group_commitment.0,
+        binding_factors: binding_factor_list.0,
+        challenge: challenge.0,
+    };
+
+    Ok((signature, details))
+}
+
+/// Report the duration of an aggregation phase started at `start`, if the
+/// caller of [`aggregate_internal`] asked for metrics.
+fn report_phase(on_metric: &mut Option<MetricSink<'_>>, phase: &str, start: Option<Instant>) {
+    if let (Some(on_metric), Some(start)) = (on_metric.as_mut(), start) {
+        on_metric(phase, start.elapsed());
+    }
+}
+
+/// Verify each signature share, returning the error for the first invalid one.
+#[cfg(feature = "cheater-detection")]
+fn verify_signature_shares<C>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    binding_factor_list: &BindingFactorList<C>,
+    challenge: &Challenge<C>,
+) -> Result<(), Error<C>>
+where
+    C: Ciphersuite,
+{
+    for (signature_share_identifier, signature_share) in signature_shares {
+        // Look up the public key for this signer, where `signer_pubkey` = _G.ScalarBaseMult(s[i])_,
+        // and where s[i] is a secret share of the constant term of _f_, the secret polynomial.
+        let signer_pubkey = pubkeys
+            .verifying_shares
+            .get(signature_share_identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+
+        // Compute Lagrange coefficient.
+        let lambda_i = derive_interpolating_value(signature_share_identifier, signing_package)?;
+
+        let binding_factor = binding_factor_list
+            .get(signature_share_identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+
+        // Compute the commitment share.
+        let R_share = signing_package
+            .signing_commitment(signature_share_identifier)
+            .ok_or(Error::UnknownIdentifier)?
+            .to_group_commitment_share(binding_factor);
+
+        // Compute relation values to verify this signature share.
+        signature_share.verify(
+            *signature_share_identifier,
+            &R_share,
+            signer_pubkey,
+            lambda_i,
+            challenge,
+        )?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,117 @@
+//! Signing the same message under multiple independent FROST groups.
+//!
+//! A participant that belongs to several groups (each with its own key) can
+//! collect, for every group, its [`KeyPackage`], the [`SigningNonces`] it
+//! generated in round one, and the [`SigningPackage`] received from that
+//! group's coordinator into a [`MultiGroupSession`], and produce all its
+//! signature shares in one call. A coordinator serving all the groups can then
+//! use [`aggregate_groups`] to produce one [`Signature`] per group.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    keys::{KeyPackage, PublicKeyPackage},
+    round1::SigningNonces,
+    round2::{self, SignatureShare},
+    Ciphersuite, Error, Identifier, Signature, SigningPackage,
+};
+
+/// The signing state of a participant for a single group.
+type GroupSigningState<C> = (KeyPackage<C>, SigningNonces<C>, SigningPackage<C>);
+
+/// A participant's signing state for several groups, keyed by a
+/// caller-chosen group identifier `G`.
+#[derive(Clone, Debug)]
+pub struct MultiGroupSession<C: Ciphersuite, G: Ord> {
+    groups: BTreeMap<G, GroupSigningState<C>>,
+}
+
+impl<C, G> MultiGroupSession<C, G>
+where
+    C: Ciphersuite,
+    G: Ord + Clone,
+{
+    /// Create a new, empty [`MultiGroupSession`].
+    pub fn new() -> Self {
+        Self {
+            groups: BTreeMap::new(),
+        }
+    }
+
+    /// Add the signing state for the given group, replacing any state
+    /// previously added for it.
+    pub fn insert(
+        &mut self,
+        group_id: G,
+        key_package: KeyPackage<C>,
+        signing_nonces: SigningNonces<C>,
+        signing_package: SigningPackage<C>,
+    ) {
+        self.groups
+            .insert(group_id, (key_package, signing_nonces, signing_package));
+    }
+
+    /// Return the number of groups in this session.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Return true if no group was added to this session.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Run [`round2::sign`] for every group in the session, returning the
+    /// participant's signature share for each group.
+    pub fn sign(&self) -> Result<BTreeMap<G, SignatureShare<C>>, Error<C>> {
+        self.groups
+            .iter()
+            .map(
+                |(group_id, (key_package, signing_nonces, signing_package))| {
+                    let signature_share =
+                        round2::sign(signing_package, signing_nonces, key_package)?;
+                    Ok((group_id.clone(), signature_share))
+                },
+            )
+            .collect()
+    }
+}
+
+impl<C, G> Default for MultiGroupSession<C, G>
+where
+    C: Ciphersuite,
+    G: Ord + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Run [`crate::aggregate`] for every group, returning one [`Signature`] per
+/// group.
+///
+/// Returns [`Error::PackageNotFound`] if the signature shares or public key
+/// package of a group with a signing package are missing.
+pub fn aggregate_groups<C, G>(
+    signing_packages: &BTreeMap<G, SigningPackage<C>>,
+    signature_shares: &BTreeMap<G, BTreeMap<Identifier<C>, SignatureShare<C>>>,
+    pubkey_packages: &BTreeMap<G, PublicKeyPackage<C>>,
+) -> Result<BTreeMap<G, Signature<C>>, Error<C>>
+where
+    C: Ciphersuite,
+    G: Ord + Clone,
+{
+    signing_packages
+        .iter()
+        .map(|(group_id, signing_package)| {
+            let signature_shares = signature_shares
+                .get(group_id)
+                .ok_or(Error::PackageNotFound)?;
+            let pubkeys = pubkey_packages
+                .get(group_id)
+                .ok_or(Error::PackageNotFound)?;
+            let signature = crate::aggregate(signing_package, signature_shares, pubkeys)?;
+            Ok((group_id.clone(), signature))
+        })
+        .collect()
+}
@@ -1,7 +1,7 @@
 //! FROST Round 1 functionality and types
 
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet, VecDeque},
     fmt::{self, Debug},
 };
 
@@ -12,7 +12,10 @@ use hex::FromHex;
 use rand_core::{CryptoRng, RngCore};
 use zeroize::Zeroize;
 
-use crate::{Ciphersuite, Element, Error, Field, Group, Header, Scalar};
+use crate::{
+    Ciphersuite, Element, Error, Field, Group, Header, Scalar, Signature, SigningKey,
+    SigningPackage, VerifyingKey,
+};
 
 #[cfg(feature = "serde")]
 use crate::serialization::{ElementSerialization, ScalarSerialization};
@@ -20,7 +23,10 @@ use crate::serialization::{ElementSerialization, ScalarSerialization};
 #[cfg(feature = "serialization")]
 use crate::serialization::{Deserialize, Serialize};
 
-use super::{keys::SigningShare, Identifier};
+use super::{
+    keys::{KeyPackage, SigningShare, VerifyingShare},
+    Identifier,
+};
 
 /// A scalar that is a signing nonce.
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -53,17 +59,55 @@ where
         Self::nonce_generate_from_random_bytes(secret, random_bytes)
     }
 
+    /// Generates a new uniformly random signing nonce, mixing in additional
+    /// entropy `aux` (e.g. supplied by an HSM).
+    ///
+    /// The nonce is derived by hashing the RNG output together with the secret
+    /// share and `aux`, so that a partially predictable RNG does not fully
+    /// determine the nonce. With an empty `aux` this is identical to
+    /// [`Nonce::new`].
+    pub fn new_with_aux<R>(secret: &SigningShare<C>, aux: &[u8], rng: &mut R) -> Self
+    where
+        R: CryptoRng + RngCore,
+    {
+        let mut random_bytes = [0; 32];
+        rng.fill_bytes(&mut random_bytes[..]);
+
+        Self::nonce_generate_from_random_bytes_with_aux(secret, random_bytes, aux)
+    }
+
+    /// Derives a signing nonce from a `seed`, a `counter` and a `domain`
+    /// byte distinguishing the hiding and binding nonces.
+    fn from_seed(secret: &SigningShare<C>, seed: [u8; 32], counter: u64, domain: u8) -> Self {
+        let mut aux = b"FROST-nonce-seed".to_vec();
+        aux.extend_from_slice(&counter.to_le_bytes());
+        aux.push(domain);
+
+        Self::nonce_generate_from_random_bytes_with_aux(secret, seed, &aux)
+    }
+
     /// Generates a nonce from the given random bytes.
     /// This function allows testing and MUST NOT be made public.
     pub(crate) fn nonce_generate_from_random_bytes(
         secret: &SigningShare<C>,
         random_bytes: [u8; 32],
+    ) -> Self {
+        Self::nonce_generate_from_random_bytes_with_aux(secret, random_bytes, &[])
+    }
+
+    /// Generates a nonce from the given random bytes and additional entropy.
+    /// This function allows testing and MUST NOT be made public.
+    fn nonce_generate_from_random_bytes_with_aux(
+        secret: &SigningShare<C>,
+        random_bytes: [u8; 32],
+        aux: &[u8],
     ) -> Self {
         let secret_enc = <<C::Group as Group>::Field>::serialize(&secret.0);
 
         let input: Vec<u8> = random_bytes
             .iter()
             .chain(secret_enc.as_ref().iter())
+            .chain(aux.iter())
             .cloned()
             .collect();
 
@@ -269,6 +313,28 @@ where
         Self::from_nonces(hiding, binding)
     }
 
+    /// Generates a new signing nonce, mixing in additional entropy `aux`.
+    ///
+    /// See [`Nonce::new_with_aux`].
+    pub fn new_with_aux<R>(secret: &SigningShare<C>, aux: &[u8], rng: &mut R) -> Self
+    where
+        R: CryptoRng + RngCore,
+    {
+        let hiding = Nonce::<C>::new_with_aux(secret, aux, rng);
+        let binding = Nonce::<C>::new_with_aux(secret, aux, rng);
+
+        Self::from_nonces(hiding, binding)
+    }
+
+    /// Derives [`SigningNonces`] deterministically from a per-signer `seed`
+    /// and a `counter`; see [`commit_from_seed`].
+    pub fn from_seed(secret: &SigningShare<C>, seed: [u8; 32], counter: u64) -> Self {
+        let hiding = Nonce::<C>::from_seed(secret, seed, counter, 0);
+        let binding = Nonce::<C>::from_seed(secret, seed, counter, 1);
+
+        Self::from_nonces(hiding, binding)
+    }
+
     /// Generates a new [`SigningNonces`] from a pair of [`Nonce`].
     ///
     /// # Security
@@ -288,6 +354,36 @@ where
             commitments,
         }
     }
+
+    /// Check that the given commitments are the commitments to these nonces,
+    /// i.e. that each commitment is its nonce times the group generator.
+    ///
+    /// This is useful to check the self-consistency of a [`SigningNonces`] and
+    /// [`SigningCommitments`] pair returned by e.g. a remote or hardware
+    /// implementation of [`commit`].
+    pub fn verify_commitments(&self, commitments: &SigningCommitments<C>) -> bool {
+        NonceCommitment::from(&self.hiding) == commitments.hiding
+            && NonceCommitment::from(&self.binding) == commitments.binding
+    }
+
+    /// Discard these nonces when their signing session is cancelled.
+    ///
+    /// The nonces are consumed and zeroized, so they can't be used afterwards.
+    /// If `nonce_pool` holds a copy of them (e.g. because they were generated
+    /// with [`preprocess`]), it is removed and zeroized as if it had been used.
+    /// Note that other clones are not affected.
+    pub fn abort(mut self, nonce_pool: Option<&mut NoncePool<C>>) {
+        if let Some(mut pooled) = nonce_pool.and_then(|pool| pool.take(&self.commitments)) {
+            pooled.zeroize();
+        }
+        self.zeroize();
+    }
+
+    /// Return true if these nonces were zeroized.
+    pub(crate) fn is_aborted(&self) -> bool {
+        let zero = <<C::Group as Group>::Field>::zero();
+        self.hiding.0 == zero && self.binding.0 == zero
+    }
 }
 
 impl<C> Debug for SigningNonces<C>
@@ -351,11 +447,14 @@ where
 
     /// Computes the [signature commitment share] from these round one signing commitments.
     ///
+    /// `binding_factor` must be the binding factor _rho_ of this participant
+    /// computed from the same [`SigningPackage`](crate::SigningPackage) these
+    /// commitments were included in (see
+    /// [`SigningPackage::binding_factor_preimages`](crate::SigningPackage::binding_factor_preimages));
+    /// otherwise the result is meaningless.
+    ///
     /// [signature commitment share]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-signature-share-verificatio
-    #[cfg(any(feature = "cheater-detection", feature = "internals"))]
-    #[cfg_attr(feature = "internals", visibility::make(pub))]
-    #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
-    pub(super) fn to_group_commitment_share(
+    pub fn to_group_commitment_share(
         self,
         binding_factor: &crate::BindingFactor<C>,
     ) -> GroupCommitmentShare<C> {
@@ -388,11 +487,239 @@ where
     }
 }
 
+impl<C> SigningCommitments<C>
+where
+    C: Ciphersuite,
+{
+    /// Sign these commitments with the signing share of `key_package`, so that
+    /// they can be published ahead of signing and later authenticated with
+    /// [`SigningPackage::verify_signed_commitments`].
+    pub fn signed<R: RngCore + CryptoRng>(
+        &self,
+        key_package: &KeyPackage<C>,
+        rng: &mut R,
+    ) -> SignedCommitments<C> {
+        let signature =
+            SigningKey::from_scalar(key_package.signing_share.0).sign(rng, &self.signed_message());
+
+        SignedCommitments::new(*self, signature)
+    }
+
+    /// Return the message signed by [`SigningCommitments::signed`], which is
+    /// the ciphersuite ID and a domain separator followed by the serialized
+    /// hiding and binding commitments.
+    fn signed_message(&self) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.extend_from_slice(C::ID.as_bytes());
+        message.extend_from_slice(b"signed-commitments");
+        message.extend_from_slice(self.hiding.serialize().as_ref());
+        message.extend_from_slice(self.binding.serialize().as_ref());
+        message
+    }
+}
+
+/// [`SigningCommitments`] signed by their signer with
+/// [`SigningCommitments::signed`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Getters)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct SignedCommitments<C: Ciphersuite> {
+    /// Serialization header
+    #[getter(skip)]
+    pub(crate) header: Header<C>,
+    /// The signed commitments.
+    pub(crate) commitments: SigningCommitments<C>,
+    /// The signature over the commitments under the signer's signing share.
+    pub(crate) signature: Signature<C>,
+}
+
+impl<C> SignedCommitments<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a [`SignedCommitments`] from its parts, e.g. after receiving
+    /// them separately.
+    pub fn new(commitments: SigningCommitments<C>, signature: Signature<C>) -> Self {
+        Self {
+            header: Header::default(),
+            commitments,
+            signature,
+        }
+    }
+
+    /// Verify the signature over the commitments with the signer's verifying
+    /// share.
+    pub fn verify(&self, verifying_share: &VerifyingShare<C>) -> Result<(), Error<C>> {
+        VerifyingKey::new(verifying_share.0)
+            .verify(&self.commitments.signed_message(), &self.signature)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> SignedCommitments<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+}
+
+/// A pool of preprocessed [`SigningCommitments`] (see [`preprocess`]) kept by
+/// the coordinator, from which one commitment per signer is drawn for each
+/// signing operation.
+///
+/// Commitments are removed from the pool when drawn, so that each one is used
+/// for at most one signing operation. Commitments are drawn in the order they
+/// were added, which allows each participant to find the matching
+/// [`SigningNonces`] for a [`SigningPackage`] built from the pool.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentPool<C: Ciphersuite> {
+    commitments: BTreeMap<Identifier<C>, VecDeque<SigningCommitments<C>>>,
+}
+
+impl<C> CommitmentPool<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new, empty [`CommitmentPool`].
+    pub fn new() -> Self {
+        Self {
+            commitments: BTreeMap::new(),
+        }
+    }
+
+    /// Add commitments published by the participant with the given identifier.
+    pub fn add(&mut self, identifier: Identifier<C>, commitments: Vec<SigningCommitments<C>>) {
+        self.commitments
+            .entry(identifier)
+            .or_default()
+            .extend(commitments);
+    }
+
+    /// Return the number of unused commitments for the given participant.
+    pub fn available(&self, identifier: &Identifier<C>) -> usize {
+        self.commitments.get(identifier).map_or(0, |c| c.len())
+    }
+
+    /// Remove and return the oldest unused commitment for the given participant,
+    /// or None if there is none left.
+    pub fn take_one(&mut self, identifier: &Identifier<C>) -> Option<SigningCommitments<C>> {
+        self.commitments.get_mut(identifier)?.pop_front()
+    }
+
+    /// Create a [`SigningPackage`] for the given signers and message, drawing one
+    /// commitment per signer from the pool.
+    ///
+    /// Returns [`Error::MissingCommitment`] without drawing any commitment if
+    /// some signer has no unused commitment left.
+    pub fn signing_package(
+        &mut self,
+        signers: &BTreeSet<Identifier<C>>,
+        message: &[u8],
+    ) -> Result<SigningPackage<C>, Error<C>> {
+        if signers.iter().any(|id| self.available(id) == 0) {
+            return Err(Error::MissingCommitment);
+        }
+        let signing_commitments = signers
+            .iter()
+            .map(|id| {
+                self.take_one(id)
+                    .map(|commitment| (*id, commitment))
+                    .ok_or(Error::MissingCommitment)
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+        Ok(SigningPackage::new(signing_commitments, message))
+    }
+}
+
+impl<C> Default for CommitmentPool<C>
+where
+    C: Ciphersuite,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The [`SigningNonces`] generated by a participant with [`preprocess`], kept
+/// secret by them until the matching [`SigningCommitments`] are drawn from a
+/// [`CommitmentPool`] by the coordinator.
+///
+/// Nonces are removed from the pool when used, so that each one is used for at
+/// most one signing operation. See
+/// [`round2::sign_preprocessed`](crate::round2::sign_preprocessed).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoncePool<C: Ciphersuite> {
+    nonces: Vec<SigningNonces<C>>,
+}
+
+impl<C> NoncePool<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`NoncePool`] holding the given nonces.
+    pub fn new(nonces: Vec<SigningNonces<C>>) -> Self {
+        Self { nonces }
+    }
+
+    /// Add nonces to the pool.
+    pub fn add(&mut self, nonces: Vec<SigningNonces<C>>) {
+        self.nonces.extend(nonces);
+    }
+
+    /// Return the number of unused nonces in the pool.
+    pub fn len(&self) -> usize {
+        self.nonces.len()
+    }
+
+    /// Return true if there are no unused nonces in the pool.
+    pub fn is_empty(&self) -> bool {
+        self.nonces.is_empty()
+    }
+
+    /// Remove and return the nonces matching the given commitments, or None
+    /// if there are none in the pool (e.g. because they were already used).
+    pub fn take(&mut self, commitments: &SigningCommitments<C>) -> Option<SigningNonces<C>> {
+        let index = self
+            .nonces
+            .iter()
+            .position(|nonces| nonces.commitments == *commitments)?;
+        Some(self.nonces.remove(index))
+    }
+}
+
+impl<C> Default for NoncePool<C>
+where
+    C: Ciphersuite,
+{
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
 /// One signer's share of the group commitment, derived from their individual signing commitments
 /// and the binding factor _rho_.
 #[derive(Clone, Copy, PartialEq)]
 pub struct GroupCommitmentShare<C: Ciphersuite>(pub(super) Element<C>);
 
+impl<C> GroupCommitmentShare<C>
+where
+    C: Ciphersuite,
+{
+    /// Return the underlying element.
+    pub fn to_element(self) -> Element<C> {
+        self.0
+    }
+}
+
 /// Encode the list of group signing commitments.
 ///
 /// Implements [`encode_group_commitment_list()`] from the spec.
@@ -472,3 +799,74 @@ where
         vec_signing_commitments.pop().expect("must have 1 element"),
     )
 }
+
+/// Like [`commit`], but mixes additional entropy `aux` (e.g. supplied by an
+/// HSM) into the nonce generation.
+///
+/// Each nonce is derived by hashing the RNG output together with the secret
+/// share and `aux` (a hedged-nonce scheme), so even a partially predictable RNG
+/// does not fully determine the nonces. `aux` does not need to be secret, but
+/// the RNG must still be a cryptographically secure one.
+pub fn commit_with_aux<C, R>(
+    secret: &SigningShare<C>,
+    aux: &[u8],
+    rng: &mut R,
+) -> (SigningNonces<C>, SigningCommitments<C>)
+where
+    C: Ciphersuite,
+    R: CryptoRng + RngCore,
+{
+    let signing_nonces = SigningNonces::new_with_aux(secret, aux, rng);
+    let signing_commitments = SigningCommitments::from(&signing_nonces);
+
+    (signing_nonces, signing_commitments)
+}
+
+/// Like [`commit`], but derives the nonces deterministically from a
+/// per-signer `seed` and a `counter`, instead of from an RNG.
+///
+/// The same `secret`, `seed` and `counter` always produce the same nonces, so
+/// an auditor given the seeds and counters used in a signing session can
+/// recompute every signer's nonces, and thus the exact signature.
+///
+/// # Security
+///
+/// The seed is as sensitive as the signing share: anyone who learns it can
+/// recompute the nonces of past signings and, with the corresponding
+/// signature shares, recover the signing share. It MUST be generated
+/// uniformly at random and kept secret, and a `counter` value MUST NEVER be
+/// used for more than one signing with the same seed, since reusing nonces
+/// leaks the signing share.
+pub fn commit_from_seed<C>(
+    secret: &SigningShare<C>,
+    seed: [u8; 32],
+    counter: u64,
+) -> (SigningNonces<C>, SigningCommitments<C>)
+where
+    C: Ciphersuite,
+{
+    let signing_nonces = SigningNonces::from_seed(secret, seed, counter);
+    let signing_commitments = SigningCommitments::from(&signing_nonces);
+
+    (signing_nonces, signing_commitments)
+}
+
+/// Like [`commit`], but mixes the identifier of the signing session into the
+/// nonces, to be used with
+/// [`round2::sign_with_session_id`](crate::round2::sign_with_session_id).
+///
+/// See [`SigningNonces::new_with_aux`].
+pub fn commit_with_session_id<C, R>(
+    secret: &SigningShare<C>,
+    session_id: &[u8; 32],
+    rng: &mut R,
+) -> (SigningNonces<C>, SigningCommitments<C>)
+where
+    C: Ciphersuite,
+    R: CryptoRng + RngCore,
+{
+    let signing_nonces = SigningNonces::new_with_aux(secret, session_id, rng);
+    let signing_commitments = SigningCommitments::from(&signing_nonces);
+
+    (signing_nonces, signing_commitments)
+}
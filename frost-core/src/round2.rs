@@ -1,15 +1,25 @@
 //! FROST Round 2 functionality and types, for signature share generation
 
-use std::fmt::{self, Debug};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Debug},
+};
+
+use rand_core::{CryptoRng, RngCore};
 
 use crate as frost;
 use crate::{
-    challenge, Challenge, Ciphersuite, Error, Field, Group, {round1, *},
+    challenge,
+    scalar_mul::VartimeMultiscalarMul,
+    Challenge, Ciphersuite, Error, Field, Group, {round1, *},
 };
 
 #[cfg(feature = "serde")]
 use crate::serialization::ScalarSerialization;
 
+#[cfg(feature = "serialization")]
+use crate::serialization::{Deserialize, Serialize};
+
 // Used to help encoding a SignatureShare. Since it has a Scalar<C> it can't
 // be directly encoded with serde, so we use this struct to wrap the scalar.
 #[cfg(feature = "serde")]
@@ -62,6 +72,10 @@ where
     C: Ciphersuite,
 {
     /// Deserialize [`SignatureShare`] from bytes
+    ///
+    /// The bytes are the plain encoding of the `z_share` scalar, as returned
+    /// by [`SignatureShare::serialize`]; the participant identifier is not
+    /// included and must be tracked separately.
     pub fn deserialize(
         bytes: <<C::Group as Group>::Field as Field>::Serialization,
     ) -> Result<Self, Error<C>> {
@@ -71,17 +85,64 @@ where
     }
 
     /// Serialize [`SignatureShare`] to bytes
+    ///
+    /// This is the plain encoding of the `z_share` scalar, so that external
+    /// verifiers can reconstruct the aggregate `z` by summing the shares.
     pub fn serialize(&self) -> <<C::Group as Group>::Field as Field>::Serialization {
         <<C::Group as Group>::Field>::serialize(&self.share)
     }
 
+    /// Serialize [`SignatureShare`] together with the identifier of the
+    /// participant that produced it, so that it can be sent over channels that
+    /// don't identify the sender.
+    ///
+    /// The encoding is the serialized identifier followed by the serialized
+    /// share; see [`SignatureShare::deserialize_with_identifier`].
+    pub fn serialize_with_identifier(&self, identifier: Identifier<C>) -> Vec<u8> {
+        let mut bytes = identifier.serialize().as_ref().to_vec();
+        bytes.extend_from_slice(self.serialize().as_ref());
+        bytes
+    }
+
+    /// Deserialize a [`SignatureShare`] and the identifier of the participant
+    /// that produced it from bytes returned by
+    /// [`SignatureShare::serialize_with_identifier`].
+    ///
+    /// Note that the identifier is not authenticated: the share must still be
+    /// verified (e.g. by [`aggregate`](crate::aggregate)) against the verifying
+    /// share of the claimed participant.
+    pub fn deserialize_with_identifier(bytes: &[u8]) -> Result<(Identifier<C>, Self), Error<C>> {
+        let scalar_len =
+            <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero())
+                .as_ref()
+                .len();
+        if bytes.len() != 2 * scalar_len {
+            return Err(Error::DeserializationError);
+        }
+        let (identifier, share) = bytes.split_at(scalar_len);
+
+        let identifier = Identifier::deserialize(
+            &identifier
+                .to_vec()
+                .try_into()
+                .map_err(|_| Error::DeserializationError)?,
+        )?;
+        let share = Self::deserialize(
+            share
+                .to_vec()
+                .try_into()
+                .map_err(|_| Error::DeserializationError)?,
+        )?;
+
+        Ok((identifier, share))
+    }
+
     /// Tests if a signature share issued by a participant is valid before
     /// aggregating it into a final joint signature to publish.
     ///
     /// This is the final step of [`verify_signature_share`] from the spec.
     ///
     /// [`verify_signature_share`]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-signature-share-verificatio
-    #[cfg(any(feature = "cheater-detection", feature = "internals"))]
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
     pub(crate) fn verify(
@@ -150,6 +211,277 @@ where
     }
 }
 
+impl<C> SignatureShare<C>
+where
+    C: Ciphersuite,
+{
+    /// Bundle a signature share with the values it is verified against: the
+    /// signer's commitment share `R_share`, the group commitment `R`, the set
+    /// of signers and the digest `H4(message)` of the message, all taken from
+    /// the signing package.
+    ///
+    /// The returned [`SignatureShareWithProof`] can be serialized to be
+    /// relayed, and must be checked by a party holding the signing package
+    /// with [`SignatureShareWithProof::verify_with_signing_package`].
+    pub fn with_proof(
+        signing_package: &SigningPackage<C>,
+        verifying_key: &frost::VerifyingKey<C>,
+        identifier: Identifier<C>,
+        share: SignatureShare<C>,
+    ) -> Result<SignatureShareWithProof<C>, Error<C>> {
+        Self::with_proof_internal(signing_package, verifying_key, identifier, share, &[])
+    }
+
+    /// Like [`SignatureShare::with_proof`], for a signature share produced
+    /// with [`sign_with_session_id`] for the given `session_id`.
+    pub fn with_proof_and_session_id(
+        signing_package: &SigningPackage<C>,
+        verifying_key: &frost::VerifyingKey<C>,
+        identifier: Identifier<C>,
+        share: SignatureShare<C>,
+        session_id: &[u8; 32],
+    ) -> Result<SignatureShareWithProof<C>, Error<C>> {
+        Self::with_proof_internal(
+            signing_package,
+            verifying_key,
+            identifier,
+            share,
+            session_id,
+        )
+    }
+
+    fn with_proof_internal(
+        signing_package: &SigningPackage<C>,
+        verifying_key: &frost::VerifyingKey<C>,
+        identifier: Identifier<C>,
+        share: SignatureShare<C>,
+        additional_prefix: &[u8],
+    ) -> Result<SignatureShareWithProof<C>, Error<C>> {
+        signing_package.validate()?;
+
+        let binding_factor_list: BindingFactorList<C> =
+            compute_binding_factor_list(signing_package, verifying_key, additional_prefix);
+        let binding_factor = binding_factor_list
+            .get(&identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+        let commitment_share = signing_package
+            .signing_commitment(&identifier)
+            .ok_or(Error::UnknownIdentifier)?
+            .to_group_commitment_share(binding_factor)
+            .to_element();
+        let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
+
+        Ok(SignatureShareWithProof {
+            header: Header::default(),
+            identifier,
+            share,
+            signers: signing_package
+                .signing_commitments()
+                .keys()
+                .cloned()
+                .collect(),
+            message_digest: signing_package.message_digest().as_ref().to_vec(),
+            group_commitment: group_commitment.0,
+            commitment_share,
+        })
+    }
+}
+
+/// A [`SignatureShare`] bundled with the signer's commitment share `R_share`,
+/// the group commitment `R`, the set of signers and the digest of the message.
+///
+/// The bundled values are not authenticated: anyone able to choose `R_share`
+/// and `R` can make any share consistent with them. A bundle is therefore
+/// only verified against the [`SigningPackage`] it was made for, with
+/// [`verify_with_signing_package`](Self::verify_with_signing_package), which
+/// recomputes `R_share` and `R` from the signers' commitments.
+#[derive(Clone, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct SignatureShareWithProof<C: Ciphersuite> {
+    /// Serialization header
+    #[getter(skip)]
+    pub(crate) header: Header<C>,
+    /// The identifier of the participant that produced the share.
+    pub(crate) identifier: Identifier<C>,
+    /// The signature share.
+    pub(crate) share: SignatureShare<C>,
+    /// The identifiers of the signers of the signing operation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::signers::deserialize")
+    )]
+    pub(crate) signers: BTreeSet<Identifier<C>>,
+    /// The digest `H4(message)` of the message being signed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serdect::slice::serialize_hex_lower_or_bin",
+            deserialize_with = "crate::serialization::message::deserialize"
+        )
+    )]
+    pub(crate) message_digest: Vec<u8>,
+    /// The group commitment, `R`.
+    #[getter(skip)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serialization::element::serialize::<C, _>",
+            deserialize_with = "crate::serialization::element::deserialize::<C, _>"
+        )
+    )]
+    pub(crate) group_commitment: Element<C>,
+    /// The signer's share of the group commitment, `R_share`.
+    #[getter(skip)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serialization::element::serialize::<C, _>",
+            deserialize_with = "crate::serialization::element::deserialize::<C, _>"
+        )
+    )]
+    pub(crate) commitment_share: Element<C>,
+}
+
+#[cfg(feature = "serialization")]
+impl<C> SignatureShareWithProof<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+}
+
+impl<C> SignatureShareWithProof<C>
+where
+    C: Ciphersuite,
+{
+    /// Check the bundled signature share against the bundled values, the
+    /// `message` and the group `verifying_key`, recomputing the challenge `c`
+    /// and the signer's Lagrange coefficient `lambda_i`.
+    ///
+    /// This does not authenticate the bundled `R_share` and `R`, so it must
+    /// only be called after they were checked against the signing package.
+    pub(crate) fn verify_with_proof(
+        &self,
+        verifying_share: &frost::keys::VerifyingShare<C>,
+        verifying_key: &frost::VerifyingKey<C>,
+        message: &[u8],
+    ) -> Result<(), Error<C>> {
+        if C::H4(message).as_ref() != self.message_digest.as_slice() {
+            return Err(Error::MessageDigestMismatch);
+        }
+
+        let lambda_i = frost::compute_lagrange_coefficient(&self.signers, None, self.identifier)?;
+        let challenge = challenge::<C>(&self.group_commitment, verifying_key, message);
+
+        self.share.verify(
+            self.identifier,
+            &round1::GroupCommitmentShare(self.commitment_share),
+            verifying_share,
+            lambda_i,
+            &challenge,
+        )
+    }
+
+    /// Verify the bundle against the `signing_package` it was made for, and
+    /// then the bundled signature share against the signer's
+    /// [`VerifyingShare`](frost::keys::VerifyingShare), checking that
+    /// `z_i * G == R_share + c * lambda_i * Y_i`.
+    ///
+    /// This is how a party holding the signing package, e.g. the coordinator,
+    /// checks a bundle received through an untrusted relay. Returns
+    /// [`Error::IncorrectCommitment`] if the bundled `R_share`, `R` or set of
+    /// signers don't match the ones derived from the signing package, and
+    /// [`Error::MessageDigestMismatch`] if the bundled message digest doesn't
+    /// match its message.
+    pub fn verify_with_signing_package(
+        &self,
+        signing_package: &SigningPackage<C>,
+        verifying_share: &frost::keys::VerifyingShare<C>,
+        verifying_key: &frost::VerifyingKey<C>,
+    ) -> Result<(), Error<C>> {
+        self.verify_with_signing_package_internal(
+            signing_package,
+            verifying_share,
+            verifying_key,
+            &[],
+        )
+    }
+
+    /// Like [`verify_with_signing_package`](Self::verify_with_signing_package),
+    /// for a signature share produced with [`sign_with_session_id`] for the
+    /// given `session_id`.
+    pub fn verify_with_signing_package_and_session_id(
+        &self,
+        signing_package: &SigningPackage<C>,
+        verifying_share: &frost::keys::VerifyingShare<C>,
+        verifying_key: &frost::VerifyingKey<C>,
+        session_id: &[u8; 32],
+    ) -> Result<(), Error<C>> {
+        self.verify_with_signing_package_internal(
+            signing_package,
+            verifying_share,
+            verifying_key,
+            session_id,
+        )
+    }
+
+    fn verify_with_signing_package_internal(
+        &self,
+        signing_package: &SigningPackage<C>,
+        verifying_share: &frost::keys::VerifyingShare<C>,
+        verifying_key: &frost::VerifyingKey<C>,
+        additional_prefix: &[u8],
+    ) -> Result<(), Error<C>> {
+        let expected = SignatureShare::with_proof_internal(
+            signing_package,
+            verifying_key,
+            self.identifier,
+            self.share,
+            additional_prefix,
+        )?;
+        if expected.signers != self.signers
+            || expected.group_commitment != self.group_commitment
+            || expected.commitment_share != self.commitment_share
+        {
+            return Err(Error::IncorrectCommitment);
+        }
+
+        self.verify_with_proof(verifying_share, verifying_key, signing_package.message())
+    }
+}
+
+impl<C> Debug for SignatureShareWithProof<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SignatureShareWithProof")
+            .field("identifier", &self.identifier)
+            .field("share", &self.share)
+            .field("signers", &self.signers)
+            .field("message_digest", &hex::encode(&self.message_digest))
+            .field(
+                "group_commitment",
+                &hex::encode(<C::Group>::serialize(&self.group_commitment)),
+            )
+            .field(
+                "commitment_share",
+                &hex::encode(<C::Group>::serialize(&self.commitment_share)),
+            )
+            .finish()
+    }
+}
+
 /// Compute the signature share for a signing operation.
 #[cfg_attr(feature = "internals", visibility::make(pub))]
 #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
@@ -178,20 +510,181 @@ fn compute_signature_share<C: Ciphersuite>(
 /// Assumes the participant has already determined which nonce corresponds with
 /// the commitment that was assigned by the coordinator in the SigningPackage.
 ///
+/// Returns [`Error::NoncesAborted`] if `signer_nonces` were zeroized, and
+/// [`Error::MessageTooLong`] if the message is longer than
+/// [`DEFAULT_MESSAGE_LIMIT`](crate::DEFAULT_MESSAGE_LIMIT) bytes (see
+/// [`sign_with_message_limit`] to use a different limit).
+///
 /// [`sign`]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-round-two-signature-share-g
 pub fn sign<C: Ciphersuite>(
     signing_package: &SigningPackage<C>,
     signer_nonces: &round1::SigningNonces<C>,
     key_package: &frost::keys::KeyPackage<C>,
 ) -> Result<SignatureShare<C>, Error<C>> {
-    if signing_package.signing_commitments().len() < key_package.min_signers as usize {
+    sign_with_message_limit(
+        signing_package,
+        signer_nonces,
+        key_package,
+        frost::DEFAULT_MESSAGE_LIMIT,
+    )
+}
+
+/// Like [`sign`], but returns [`Error::MessageTooLong`] if the message is
+/// longer than `message_limit` bytes, instead of
+/// [`DEFAULT_MESSAGE_LIMIT`](crate::DEFAULT_MESSAGE_LIMIT).
+///
+/// This can be used by a signer to bound the resources spent on hashing the
+/// message of a signing package created by an untrusted coordinator.
+pub fn sign_with_message_limit<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    message_limit: usize,
+) -> Result<SignatureShare<C>, Error<C>> {
+    if signing_package.message().len() > message_limit {
+        return Err(Error::MessageTooLong);
+    }
+
+    let (binding_factor, lambda_i, challenge) = signing_parameters(
+        signing_package,
+        signer_nonces,
+        key_package.identifier,
+        &key_package.verifying_key,
+        key_package.min_signers,
+        key_package.capabilities,
+        &[],
+    )?;
+
+    // Compute the Schnorr signature share.
+    let signature_share = compute_signature_share(
+        signer_nonces,
+        binding_factor,
+        lambda_i,
+        key_package,
+        challenge,
+    );
+
+    Ok(signature_share)
+}
+
+/// Like [`sign`], but binds the signature share to the signing session
+/// identified by `session_id`, which is appended to the preimage of every
+/// binding factor.
+///
+/// Shares produced for one session don't aggregate in another: the
+/// coordinator must use [`aggregate_with_session_id`] with the same
+/// `session_id`. The resulting signature is a regular signature over the
+/// message. Signers should also use
+/// [`commit_with_session_id`](round1::commit_with_session_id) to generate
+/// their nonces.
+///
+/// [`aggregate_with_session_id`]: crate::aggregate_with_session_id
+pub fn sign_with_session_id<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    session_id: &[u8; 32],
+) -> Result<SignatureShare<C>, Error<C>> {
+    if signing_package.message().len() > frost::DEFAULT_MESSAGE_LIMIT {
+        return Err(Error::MessageTooLong);
+    }
+
+    let (binding_factor, lambda_i, challenge) = signing_parameters(
+        signing_package,
+        signer_nonces,
+        key_package.identifier,
+        &key_package.verifying_key,
+        key_package.min_signers,
+        key_package.capabilities,
+        session_id,
+    )?;
+
+    Ok(compute_signature_share(
+        signer_nonces,
+        binding_factor,
+        lambda_i,
+        key_package,
+        challenge,
+    ))
+}
+
+/// Like [`sign`], but uses the given `challenge` instead of deriving it with
+/// `H2(R || PK || msg)`, e.g. to compose FROST into a larger sigma protocol.
+/// The shares must be aggregated with [`aggregate_with_challenge`].
+///
+/// # Security
+///
+/// The caller is responsible for the soundness of the challenge: it MUST be
+/// unpredictable to the signers before the group commitment is fixed, and
+/// bind the group commitment, the group key and the message (or whatever the
+/// composed protocol requires). Otherwise signatures may be forgeable, e.g.
+/// with ROS-style attacks over concurrent signing sessions.
+///
+/// [`aggregate_with_challenge`]: crate::aggregate_with_challenge
+pub fn sign_with_challenge<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    challenge: Scalar<C>,
+) -> Result<SignatureShare<C>, Error<C>> {
+    if signing_package.message().len() > frost::DEFAULT_MESSAGE_LIMIT {
+        return Err(Error::MessageTooLong);
+    }
+
+    let (binding_factor, lambda_i, _) = signing_parameters(
+        signing_package,
+        signer_nonces,
+        key_package.identifier,
+        &key_package.verifying_key,
+        key_package.min_signers,
+        key_package.capabilities,
+        &[],
+    )?;
+
+    Ok(compute_signature_share(
+        signer_nonces,
+        binding_factor,
+        lambda_i,
+        key_package,
+        Challenge(challenge),
+    ))
+}
+
+/// The binding factor, Lagrange coefficient and challenge used to compute a
+/// signature share.
+type SigningParameters<C> = (BindingFactor<C>, Scalar<C>, Challenge<C>);
+
+/// Validate the signing package for the given signer and compute the values
+/// needed to produce their signature share: the binding factor, the Lagrange
+/// coefficient and the challenge.
+///
+/// Returns [`Error::MissingCapability`] if the signer's key package lacks
+/// [`Capabilities::SIGN`](frost::keys::Capabilities::SIGN).
+fn signing_parameters<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    identifier: Identifier<C>,
+    verifying_key: &frost::VerifyingKey<C>,
+    min_signers: u16,
+    capabilities: frost::keys::Capabilities,
+    additional_prefix: &[u8],
+) -> Result<SigningParameters<C>, Error<C>> {
+    if !capabilities.contains(frost::keys::Capabilities::SIGN) {
+        return Err(Error::MissingCapability);
+    }
+
+    if signing_package.signing_commitments().len() < min_signers as usize {
         return Err(Error::IncorrectNumberOfCommitments);
     }
 
+    if signer_nonces.is_aborted() {
+        return Err(Error::NoncesAborted);
+    }
+
     // Validate the signer's commitment is present in the signing package
     let commitment = signing_package
         .signing_commitments
-        .get(&key_package.identifier)
+        .get(&identifier)
         .ok_or(Error::MissingCommitment)?;
 
     // Validate if the signer's commitment exists
@@ -199,12 +692,15 @@ pub fn sign<C: Ciphersuite>(
         return Err(Error::IncorrectCommitment);
     }
 
+    // Validate the other signers' commitments before the nonces are used.
+    signing_package.validate()?;
+
     // Encodes the signing commitment list produced in round one as part of generating [`BindingFactor`], the
     // binding factor.
     let binding_factor_list: BindingFactorList<C> =
-        compute_binding_factor_list(signing_package, &key_package.verifying_key, &[]);
+        compute_binding_factor_list(signing_package, verifying_key, additional_prefix);
     let binding_factor: frost::BindingFactor<C> = binding_factor_list
-        .get(&key_package.identifier)
+        .get(&identifier)
         .ok_or(Error::UnknownIdentifier)?
         .clone();
 
@@ -212,23 +708,431 @@ pub fn sign<C: Ciphersuite>(
     let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
 
     // Compute Lagrange coefficient.
-    let lambda_i = frost::derive_interpolating_value(key_package.identifier(), signing_package)?;
+    let lambda_i = frost::derive_interpolating_value(&identifier, signing_package)?;
 
     // Compute the per-message challenge.
     let challenge = challenge::<C>(
         &group_commitment.0,
-        &key_package.verifying_key,
+        verifying_key,
         signing_package.message.as_slice(),
     );
 
-    // Compute the Schnorr signature share.
-    let signature_share = compute_signature_share(
+    Ok((binding_factor, lambda_i, challenge))
+}
+
+/// A backend that holds a participant's [`SigningShare`](frost::keys::SigningShare)
+/// and performs the operations that require it, e.g. inside an HSM, so that
+/// the raw share never needs to be exposed.
+///
+/// Implementations should return [`Error::SignerBackendFailure`] if an
+/// operation fails. See [`sign_with_backend`].
+pub trait SignerBackend<C: Ciphersuite> {
+    /// Return the participant's verifying share, i.e. `s_i * G` where `s_i` is
+    /// the signing share.
+    fn scalar_mul_base(&self) -> Result<frost::keys::VerifyingShare<C>, Error<C>>;
+
+    /// Return `s_i * scalar`, where `s_i` is the signing share.
+    fn scalar_mul(&self, scalar: &Scalar<C>) -> Result<Scalar<C>, Error<C>>;
+}
+
+/// A [`SignerBackend`] that holds the signing share in memory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SoftwareBackend<C: Ciphersuite> {
+    signing_share: frost::keys::SigningShare<C>,
+}
+
+impl<C> SoftwareBackend<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`SoftwareBackend`] holding the given signing share.
+    pub fn new(signing_share: frost::keys::SigningShare<C>) -> Self {
+        Self { signing_share }
+    }
+}
+
+impl<C> SignerBackend<C> for SoftwareBackend<C>
+where
+    C: Ciphersuite,
+{
+    fn scalar_mul_base(&self) -> Result<frost::keys::VerifyingShare<C>, Error<C>> {
+        Ok(self.signing_share.into())
+    }
+
+    fn scalar_mul(&self, scalar: &Scalar<C>) -> Result<Scalar<C>, Error<C>> {
+        Ok(self.signing_share.0 * *scalar)
+    }
+}
+
+/// Like [`sign`], but performs the operation that requires the signing share
+/// through a [`SignerBackend`], so that the share can be kept e.g. in an HSM.
+///
+/// `public_key_component` holds the public data of the participant's
+/// [`KeyPackage`](frost::keys::KeyPackage) (see
+/// [`KeyPackage::public_part`](frost::keys::KeyPackage::public_part)),
+/// including its [`Capabilities`](frost::keys::Capabilities).
+///
+/// Returns [`Error::SignerBackendMismatch`] if the verifying share computed by
+/// the backend doesn't match the one in `public_key_component`, since the
+/// backend would otherwise produce invalid signature shares.
+pub fn sign_with_backend<C: Ciphersuite, B: SignerBackend<C>>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    public_key_component: &frost::keys::PublicKeyComponent<C>,
+    backend: &B,
+) -> Result<SignatureShare<C>, Error<C>> {
+    if signing_package.message().len() > frost::DEFAULT_MESSAGE_LIMIT {
+        return Err(Error::MessageTooLong);
+    }
+
+    if backend.scalar_mul_base()? != public_key_component.verifying_share {
+        return Err(Error::SignerBackendMismatch);
+    }
+
+    let (binding_factor, lambda_i, challenge) = signing_parameters(
+        signing_package,
         signer_nonces,
-        binding_factor,
+        public_key_component.identifier,
+        &public_key_component.verifying_key,
+        public_key_component.min_signers,
+        public_key_component.capabilities,
+        &[],
+    )?;
+
+    let z_share: Scalar<C> = signer_nonces.hiding.0
+        + (signer_nonces.binding.0 * binding_factor.0)
+        + backend.scalar_mul(&(lambda_i * challenge.0))?;
+
+    Ok(SignatureShare::<C> { share: z_share })
+}
+
+/// Like [`sign`], but refuses to sign unless the digest of the message in the
+/// `signing_package` matches `message_digest`.
+///
+/// This protects against a coordinator sending different messages to
+/// different signers: each signer first publishes the
+/// [`SigningPackage::message_digest`] of the package it received, the signers
+/// agree on a single digest, and then pass it here.
+pub fn sign_with_message_digest<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    message_digest: &[u8],
+) -> Result<SignatureShare<C>, Error<C>> {
+    if signing_package.message_digest().as_ref() != message_digest {
+        return Err(Error::MessageDigestMismatch);
+    }
+
+    sign(signing_package, signer_nonces, key_package)
+}
+
+/// Like [`sign`], but for one-round signing: uses and removes from
+/// `nonce_pool` the nonces matching the commitment the coordinator drew for
+/// this participant from a [`CommitmentPool`](round1::CommitmentPool).
+///
+/// Returns [`Error::MissingCommitment`] if the `signing_package` has no
+/// commitment for this participant, and [`Error::IncorrectCommitment`] if
+/// there are no matching nonces in the pool (e.g. because they were already
+/// used).
+pub fn sign_preprocessed<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    nonce_pool: &mut round1::NoncePool<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<SignatureShare<C>, Error<C>> {
+    // Reject an invalid package before consuming the nonces.
+    signing_package.validate()?;
+
+    let commitments = signing_package
+        .signing_commitment(&key_package.identifier)
+        .ok_or(Error::MissingCommitment)?;
+    let signer_nonces = nonce_pool
+        .take(&commitments)
+        .ok_or(Error::IncorrectCommitment)?;
+
+    sign(signing_package, &signer_nonces, key_package)
+}
+
+/// Verify a single participant's signature share against their
+/// [`VerifyingShare`](frost::keys::VerifyingShare).
+///
+/// Computes the signer's Lagrange coefficient, commitment share and the
+/// challenge from the `signing_package` and the group `verifying_key`, and
+/// then performs [`verify_signature_share`] from the spec.
+///
+/// This allows a coordinator to check shares as they arrive, instead of
+/// relying on [`aggregate`](crate::aggregate) to identify invalid shares.
+///
+/// [`verify_signature_share`]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-signature-share-verificatio
+pub fn check_share<C: Ciphersuite>(
+    signature_share: &SignatureShare<C>,
+    identifier: Identifier<C>,
+    signing_package: &SigningPackage<C>,
+    verifying_share: &frost::keys::VerifyingShare<C>,
+    verifying_key: &frost::VerifyingKey<C>,
+) -> Result<(), Error<C>> {
+    check_share_internal(
+        signature_share,
+        identifier,
+        signing_package,
+        verifying_share,
+        verifying_key,
+        &[],
+    )
+}
+
+/// Like [`check_share`], for a signature share produced with
+/// [`sign_with_session_id`] for the given `session_id`.
+///
+/// A share produced for a different session (or with [`sign`]) fails to
+/// verify.
+pub fn check_share_with_session_id<C: Ciphersuite>(
+    signature_share: &SignatureShare<C>,
+    identifier: Identifier<C>,
+    signing_package: &SigningPackage<C>,
+    verifying_share: &frost::keys::VerifyingShare<C>,
+    verifying_key: &frost::VerifyingKey<C>,
+    session_id: &[u8; 32],
+) -> Result<(), Error<C>> {
+    check_share_internal(
+        signature_share,
+        identifier,
+        signing_package,
+        verifying_share,
+        verifying_key,
+        session_id,
+    )
+}
+
+fn check_share_internal<C: Ciphersuite>(
+    signature_share: &SignatureShare<C>,
+    identifier: Identifier<C>,
+    signing_package: &SigningPackage<C>,
+    verifying_share: &frost::keys::VerifyingShare<C>,
+    verifying_key: &frost::VerifyingKey<C>,
+    additional_prefix: &[u8],
+) -> Result<(), Error<C>> {
+    let (group_commitment_share, challenge, lambda_i) = share_verification_parameters(
+        signing_package,
+        verifying_key,
+        identifier,
+        additional_prefix,
+    )?;
+
+    signature_share.verify(
+        identifier,
+        &group_commitment_share,
+        verifying_share,
         lambda_i,
-        key_package,
-        challenge,
+        &challenge,
+    )
+}
+
+/// The commitment share, challenge and Lagrange coefficient a signature share
+/// is verified against.
+type ShareVerificationParameters<C> = (round1::GroupCommitmentShare<C>, Challenge<C>, Scalar<C>);
+
+/// Compute the values a signature share of the given participant is verified
+/// against: their commitment share `R_share`, the challenge and their
+/// Lagrange coefficient. The `additional_prefix` (e.g. a session ID) is
+/// appended to the preimage of every binding factor.
+fn share_verification_parameters<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    verifying_key: &frost::VerifyingKey<C>,
+    identifier: Identifier<C>,
+    additional_prefix: &[u8],
+) -> Result<ShareVerificationParameters<C>, Error<C>> {
+    signing_package.validate()?;
+
+    let binding_factor_list: BindingFactorList<C> =
+        compute_binding_factor_list(signing_package, verifying_key, additional_prefix);
+    let binding_factor = binding_factor_list
+        .get(&identifier)
+        .ok_or(Error::UnknownIdentifier)?;
+
+    let group_commitment_share = signing_package
+        .signing_commitment(&identifier)
+        .ok_or(Error::UnknownIdentifier)?
+        .to_group_commitment_share(binding_factor);
+
+    let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
+    let challenge = challenge::<C>(
+        &group_commitment.0,
+        verifying_key,
+        signing_package.message.as_slice(),
     );
 
-    Ok(signature_share)
+    let lambda_i = frost::derive_interpolating_value(&identifier, signing_package)?;
+
+    Ok((group_commitment_share, challenge, lambda_i))
+}
+
+/// The message signed by a participation receipt:
+/// `contextString || len(session_id) || session_id || H4(message)`, where
+/// `contextString` is the ciphersuite ID followed by `"participation-receipt"`
+/// and the length of `session_id` is encoded as a big-endian `u64`.
+fn participation_receipt_message<C: Ciphersuite>(session_id: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut receipt_message = Vec::new();
+    receipt_message.extend_from_slice(C::ID.as_bytes());
+    receipt_message.extend_from_slice(b"participation-receipt");
+    receipt_message.extend_from_slice(&(session_id.len() as u64).to_be_bytes());
+    receipt_message.extend_from_slice(session_id);
+    receipt_message.extend_from_slice(C::H4(message).as_ref());
+    receipt_message
+}
+
+/// Produce a receipt attesting that the participant contributed to signing
+/// `message` in the session identified by `session_id`.
+///
+/// The receipt is a plain Schnorr signature under the participant's signing
+/// share over `session_id` and `H4(message)`, prefixed with a domain tag so
+/// that it can't be mistaken for other messages signed with the share. It is
+/// unrelated to the threshold signature, and anyone can check it against the
+/// participant's [`VerifyingShare`](frost::keys::VerifyingShare) with
+/// [`verify_participation_receipt`].
+///
+/// Returns [`Error::MissingCapability`] if the key package lacks
+/// [`Capabilities::SIGN`](frost::keys::Capabilities::SIGN).
+pub fn participation_receipt<C: Ciphersuite, R: RngCore + CryptoRng>(
+    key_package: &frost::keys::KeyPackage<C>,
+    session_id: &[u8],
+    message: &[u8],
+    rng: &mut R,
+) -> Result<Signature<C>, Error<C>> {
+    if !key_package
+        .capabilities
+        .contains(frost::keys::Capabilities::SIGN)
+    {
+        return Err(Error::MissingCapability);
+    }
+
+    let signing_key = SigningKey::from_scalar(key_package.signing_share.0);
+    Ok(signing_key.sign(
+        rng,
+        &participation_receipt_message::<C>(session_id, message),
+    ))
+}
+
+/// Verify a receipt produced with [`participation_receipt`] by the participant
+/// with the given `verifying_share`.
+pub fn verify_participation_receipt<C: Ciphersuite>(
+    verifying_share: &frost::keys::VerifyingShare<C>,
+    session_id: &[u8],
+    message: &[u8],
+    receipt: &Signature<C>,
+) -> Result<(), Error<C>> {
+    VerifyingKey::new(verifying_share.0).verify(
+        &participation_receipt_message::<C>(session_id, message),
+        receipt,
+    )
+}
+
+/// Verify all the signature shares of a signing session at once.
+///
+/// The per-share verification equations `z_i * G = R_i + lambda_i * c * Y_i`
+/// are combined with random weights into a single multi-scalar multiplication,
+/// which is much faster than verifying each share with [`check_share`] when
+/// all shares are valid. If the combined check fails, the shares are verified
+/// individually to identify the culprit, which is returned in
+/// [`Error::InvalidSignatureShare`].
+///
+/// Returns [`Error::UnknownIdentifier`] if a share is from a signer without a
+/// commitment in the `signing_package` or a verifying share in `pubkeys`.
+pub fn batch_verify_shares<C: Ciphersuite, R: RngCore + CryptoRng>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, SignatureShare<C>>,
+    pubkeys: &frost::keys::PublicKeyPackage<C>,
+    rng: &mut R,
+) -> Result<(), Error<C>> {
+    batch_verify_shares_internal(signing_package, signature_shares, pubkeys, &[], rng)
+}
+
+/// Like [`batch_verify_shares`], for signature shares produced with
+/// [`sign_with_session_id`] for the given `session_id`.
+pub fn batch_verify_shares_with_session_id<C: Ciphersuite, R: RngCore + CryptoRng>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, SignatureShare<C>>,
+    pubkeys: &frost::keys::PublicKeyPackage<C>,
+    session_id: &[u8; 32],
+    rng: &mut R,
+) -> Result<(), Error<C>> {
+    batch_verify_shares_internal(signing_package, signature_shares, pubkeys, session_id, rng)
+}
+
+fn batch_verify_shares_internal<C: Ciphersuite, R: RngCore + CryptoRng>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, SignatureShare<C>>,
+    pubkeys: &frost::keys::PublicKeyPackage<C>,
+    additional_prefix: &[u8],
+    rng: &mut R,
+) -> Result<(), Error<C>> {
+    signing_package.validate()?;
+
+    let binding_factor_list: BindingFactorList<C> =
+        compute_binding_factor_list(signing_package, &pubkeys.verifying_key, additional_prefix);
+    let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
+    let challenge = challenge::<C>(
+        &group_commitment.0,
+        &pubkeys.verifying_key,
+        signing_package.message.as_slice(),
+    );
+
+    let n = signature_shares.len();
+    let mut generator_coeff = <<C::Group as Group>::Field>::zero();
+    let mut coeffs = Vec::with_capacity(3 * n);
+    let mut elements = Vec::with_capacity(3 * n);
+    // The values needed to verify each share individually, if the batch fails.
+    let mut checks = Vec::with_capacity(n);
+
+    for (identifier, signature_share) in signature_shares {
+        let verifying_share = pubkeys
+            .verifying_shares
+            .get(identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+        let binding_factor = binding_factor_list
+            .get(identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+        let commitments = signing_package
+            .signing_commitment(identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+        let lambda_i = derive_interpolating_value(identifier, signing_package)?;
+
+        // Accumulate blind * (z_i * G - D_i - rho_i * E_i - lambda_i * c * Y_i).
+        let blind = <<C::Group as Group>::Field>::random(rng);
+        generator_coeff = generator_coeff + blind * signature_share.share;
+        coeffs.push(<<C::Group as Group>::Field>::zero() - blind);
+        elements.push(commitments.hiding().0);
+        coeffs.push(<<C::Group as Group>::Field>::zero() - blind * binding_factor.0);
+        elements.push(commitments.binding().0);
+        coeffs.push(<<C::Group as Group>::Field>::zero() - blind * lambda_i * challenge.0);
+        elements.push(verifying_share.0);
+
+        checks.push((
+            identifier,
+            signature_share,
+            commitments.to_group_commitment_share(binding_factor),
+            verifying_share,
+            lambda_i,
+        ));
+    }
+
+    let check: Element<C> = VartimeMultiscalarMul::<C>::vartime_multiscalar_mul(
+        std::iter::once(&generator_coeff).chain(coeffs.iter()),
+        std::iter::once(&C::Group::generator()).chain(elements.iter()),
+    );
+    if check == C::Group::identity() {
+        return Ok(());
+    }
+
+    for (identifier, signature_share, group_commitment_share, verifying_share, lambda_i) in checks {
+        signature_share.verify(
+            *identifier,
+            &group_commitment_share,
+            verifying_share,
+            lambda_i,
+            &challenge,
+        )?;
+    }
+
+    // We should never reach here; but we return an error to be safe.
+    Err(Error::InvalidSignature)
 }
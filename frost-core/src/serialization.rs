@@ -51,6 +51,262 @@ where
     }
 }
 
+/// Serde helpers for `Scalar` fields. Since the ciphersuite can't be inferred
+/// from the field type, use them with e.g.
+/// `#[serde(serialize_with = "crate::serialization::scalar::serialize::<C, _>")]`.
+#[cfg(feature = "serde")]
+pub(crate) mod scalar {
+    use super::ScalarSerialization;
+    use crate::{Ciphersuite, Field, Group, Scalar};
+
+    pub(crate) fn serialize<C, S>(scalar: &Scalar<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Ciphersuite,
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(
+            &ScalarSerialization::<C>(<<C::Group as Group>::Field>::serialize(scalar)),
+            serializer,
+        )
+    }
+
+    pub(crate) fn deserialize<'de, C, D>(deserializer: D) -> Result<Scalar<C>, D::Error>
+    where
+        C: Ciphersuite,
+        D: serde::Deserializer<'de>,
+    {
+        let serialization: ScalarSerialization<C> = serde::Deserialize::deserialize(deserializer)?;
+        <<C::Group as Group>::Field>::deserialize(&serialization.0)
+            .map_err(|err| serde::de::Error::custom(format!("{err}")))
+    }
+}
+
+/// Serde helpers for `Vec<Scalar>` fields; see [`scalar`].
+#[cfg(feature = "serde")]
+pub(crate) mod scalar_vec {
+    use super::ScalarSerialization;
+    use crate::{Ciphersuite, Field, Group, Scalar};
+
+    pub(crate) fn serialize<C, S>(scalars: &[Scalar<C>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Ciphersuite,
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(scalars.iter().map(|scalar| {
+            ScalarSerialization::<C>(<<C::Group as Group>::Field>::serialize(scalar))
+        }))
+    }
+
+    pub(crate) fn deserialize<'de, C, D>(deserializer: D) -> Result<Vec<Scalar<C>>, D::Error>
+    where
+        C: Ciphersuite,
+        D: serde::Deserializer<'de>,
+    {
+        let serializations: Vec<ScalarSerialization<C>> =
+            serde::Deserialize::deserialize(deserializer)?;
+        serializations
+            .iter()
+            .map(|serialization| {
+                <<C::Group as Group>::Field>::deserialize(&serialization.0)
+                    .map_err(|err| serde::de::Error::custom(format!("{err}")))
+            })
+            .collect()
+    }
+}
+
+/// Serde helpers for `Element` fields; see [`scalar`].
+#[cfg(feature = "serde")]
+pub(crate) mod element {
+    use super::ElementSerialization;
+    use crate::{Ciphersuite, Element, Group};
+
+    pub(crate) fn serialize<C, S>(element: &Element<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Ciphersuite,
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(
+            &ElementSerialization::<C>(<C::Group>::serialize(element)),
+            serializer,
+        )
+    }
+
+    pub(crate) fn deserialize<'de, C, D>(deserializer: D) -> Result<Element<C>, D::Error>
+    where
+        C: Ciphersuite,
+        D: serde::Deserializer<'de>,
+    {
+        let serialization: ElementSerialization<C> = serde::Deserialize::deserialize(deserializer)?;
+        <C::Group>::deserialize(&serialization.0)
+            .map_err(|err| serde::de::Error::custom(format!("{err}")))
+    }
+}
+
+/// Serde helper for the signing commitments of a `SigningPackage`, which
+/// rejects more than [`DEFAULT_SIGNER_LIMIT`](crate::DEFAULT_SIGNER_LIMIT)
+/// entries before allocating them.
+#[cfg(feature = "serde")]
+pub(crate) mod signing_commitments {
+    use std::{collections::BTreeMap, fmt, marker::PhantomData};
+
+    use crate::{round1::SigningCommitments, Ciphersuite, Identifier, DEFAULT_SIGNER_LIMIT};
+
+    struct Visitor<C>(PhantomData<C>);
+
+    impl<'de, C> serde::de::Visitor<'de> for Visitor<C>
+    where
+        C: Ciphersuite,
+    {
+        type Value = BTreeMap<Identifier<C>, SigningCommitments<C>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a map of at most {DEFAULT_SIGNER_LIMIT} signing commitments"
+            )
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            if map.size_hint().unwrap_or(0) > DEFAULT_SIGNER_LIMIT {
+                return Err(serde::de::Error::custom("too many signers"));
+            }
+            let mut signing_commitments = BTreeMap::new();
+            while let Some((identifier, commitments)) = map.next_entry()? {
+                if signing_commitments.len() == DEFAULT_SIGNER_LIMIT {
+                    return Err(serde::de::Error::custom("too many signers"));
+                }
+                signing_commitments.insert(identifier, commitments);
+            }
+            Ok(signing_commitments)
+        }
+    }
+
+    pub(crate) fn deserialize<'de, C, D>(
+        deserializer: D,
+    ) -> Result<BTreeMap<Identifier<C>, SigningCommitments<C>>, D::Error>
+    where
+        C: Ciphersuite,
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(Visitor(PhantomData))
+    }
+}
+
+/// Serde helper for a set of signer identifiers, which rejects more than
+/// [`DEFAULT_SIGNER_LIMIT`](crate::DEFAULT_SIGNER_LIMIT) entries before
+/// allocating them.
+#[cfg(feature = "serde")]
+pub(crate) mod signers {
+    use std::{collections::BTreeSet, fmt, marker::PhantomData};
+
+    use crate::{Ciphersuite, Identifier, DEFAULT_SIGNER_LIMIT};
+
+    struct Visitor<C>(PhantomData<C>);
+
+    impl<'de, C> serde::de::Visitor<'de> for Visitor<C>
+    where
+        C: Ciphersuite,
+    {
+        type Value = BTreeSet<Identifier<C>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a set of at most {DEFAULT_SIGNER_LIMIT} signers")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            if seq.size_hint().unwrap_or(0) > DEFAULT_SIGNER_LIMIT {
+                return Err(serde::de::Error::custom("too many signers"));
+            }
+            let mut signers = BTreeSet::new();
+            while let Some(identifier) = seq.next_element()? {
+                if signers.len() == DEFAULT_SIGNER_LIMIT {
+                    return Err(serde::de::Error::custom("too many signers"));
+                }
+                signers.insert(identifier);
+            }
+            Ok(signers)
+        }
+    }
+
+    pub(crate) fn deserialize<'de, C, D>(
+        deserializer: D,
+    ) -> Result<BTreeSet<Identifier<C>>, D::Error>
+    where
+        C: Ciphersuite,
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(Visitor(PhantomData))
+    }
+}
+
+/// Deserializes a [`SigningPackage`](crate::SigningPackage) message, failing
+/// if it is longer than [`DEFAULT_MESSAGE_LIMIT`](crate::DEFAULT_MESSAGE_LIMIT)
+/// bytes before allocating it. Accepts the encodings produced by
+/// `serdect::slice::serialize_hex_lower_or_bin`.
+#[cfg(feature = "serde")]
+pub(crate) mod message {
+    use std::fmt;
+
+    use crate::DEFAULT_MESSAGE_LIMIT;
+
+    struct Visitor;
+
+    impl<'de> serde::de::Visitor<'de> for Visitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a message of at most {DEFAULT_MESSAGE_LIMIT} bytes"
+            )
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            if v.len() > 2 * DEFAULT_MESSAGE_LIMIT {
+                return Err(E::custom("message too long"));
+            }
+            hex::decode(v).map_err(E::custom)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            if seq.size_hint().unwrap_or(0) > DEFAULT_MESSAGE_LIMIT {
+                return Err(serde::de::Error::custom("message too long"));
+            }
+            let mut message = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                if message.len() == DEFAULT_MESSAGE_LIMIT {
+                    return Err(serde::de::Error::custom("message too long"));
+                }
+                message.push(byte);
+            }
+            Ok(message)
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Visitor)
+        } else {
+            deserializer.deserialize_seq(Visitor)
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 pub(crate) struct ElementSerialization<C: Ciphersuite>(
     pub(crate) <<C as Ciphersuite>::Group as Group>::Serialization,
@@ -141,23 +397,47 @@ where
     }
 }
 
-/// Deserialize a version. For now, since there is a single version 0,
-/// simply validate if it's 0.
+/// The latest serialization format version.
+///
+/// Version 1 added the minimum number of signers to `PublicKeyPackage` and the
+/// capabilities to `KeyPackage` and `PublicKeyComponent`. Values that don't use
+/// any field added in version 1 are still serialized with version 0, so that
+/// previous versions of this crate can read them.
+#[cfg(feature = "serde")]
+pub(crate) const FORMAT_VERSION: u8 = 1;
+
+/// Deserialize a version, checking that it's not newer than
+/// [`FORMAT_VERSION`].
 #[cfg(feature = "serde")]
 pub(crate) fn version_deserialize<'de, D>(deserializer: D) -> Result<u8, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
     let version: u8 = serde::de::Deserialize::deserialize(deserializer)?;
-    if version != 0 {
-        Err(serde::de::Error::custom(
-            "wrong format version, only 0 supported",
-        ))
+    if version > FORMAT_VERSION {
+        Err(serde::de::Error::custom("unsupported format version"))
     } else {
         Ok(version)
     }
 }
 
+/// Deserialize the next field of a struct from a sequence, for structs whose
+/// fields depend on the format version and thus implement
+/// `serde::de::Visitor::visit_seq` manually.
+#[cfg(feature = "serde")]
+pub(crate) fn next_field<'de, A, T>(
+    seq: &mut A,
+    index: usize,
+    expected: &dyn serde::de::Expected,
+) -> Result<T, A::Error>
+where
+    A: serde::de::SeqAccess<'de>,
+    T: serde::Deserialize<'de>,
+{
+    seq.next_element()?
+        .ok_or_else(|| serde::de::Error::invalid_length(index, expected))
+}
+
 // Default byte-oriented serialization for structs that need to be communicated.
 //
 // Note that we still manually implement these methods in each applicable type,
@@ -190,6 +470,30 @@ impl<T: serde::Serialize, C: Ciphersuite> Serialize<C> for T {
 #[cfg(feature = "serialization")]
 impl<T: for<'de> serde::Deserialize<'de>, C: Ciphersuite> Deserialize<C> for T {
     fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
-        postcard::from_bytes(bytes).map_err(|_| Error::DeserializationError)
+        postcard::from_bytes(bytes).map_err(|_| {
+            // All serialized structs start with a `Header`. If it has an
+            // unknown version or refers to a different ciphersuite, but the
+            // value is otherwise well-formed (i.e. it deserializes with a
+            // valid header), report that instead of a generic error.
+            let Ok(((version, id), body)) = postcard::take_from_bytes::<(u8, [u8; 4])>(bytes)
+            else {
+                return Error::DeserializationError;
+            };
+            let well_formed = (0..=FORMAT_VERSION).any(|valid_version| {
+                let mut candidate = vec![valid_version];
+                candidate.extend_from_slice(&short_id::<C>());
+                candidate.extend_from_slice(body);
+                postcard::from_bytes::<T>(&candidate).is_ok()
+            });
+            if !well_formed {
+                Error::DeserializationError
+            } else if version > FORMAT_VERSION {
+                Error::UnsupportedVersion
+            } else if id != short_id::<C>() {
+                Error::CiphersuiteMismatch
+            } else {
+                Error::DeserializationError
+            }
+        })
     }
 }
@@ -1,8 +1,9 @@
 //! Schnorr signatures over prime order groups (or subgroups)
 
 use debugless_unwrap::DebuglessUnwrap;
+use rand_core::{CryptoRng, RngCore};
 
-use crate::{Ciphersuite, Element, Error, Field, Group, Scalar};
+use crate::{batch, Ciphersuite, Element, Error, Field, Group, Scalar, VerifyingKey};
 
 /// A Schnorr signature over some prime order group (or subgroup).
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -29,6 +30,20 @@ where
         Self { R, z }
     }
 
+    /// Reject degenerate signatures, i.e. those whose commitment `R` is the
+    /// group identity or whose response `z` is zero. These must never verify,
+    /// and are rejected explicitly rather than relying on the verification
+    /// equation failing.
+    pub(crate) fn check_non_degenerate(&self) -> Result<(), Error<C>> {
+        if self.R == <C::Group>::identity() {
+            return Err(Error::IdentitySignatureCommitment);
+        }
+        if self.z == <<C::Group as Group>::Field>::zero() {
+            return Err(Error::ZeroSignatureResponse);
+        }
+        Ok(())
+    }
+
     /// Converts bytes as [`Ciphersuite::SignatureSerialization`] into a `Signature<C>`.
     pub fn deserialize(bytes: C::SignatureSerialization) -> Result<Self, Error<C>> {
         // To compute the expected length of the encoded point, encode the generator
@@ -79,8 +94,91 @@ where
 
         bytes.try_into().debugless_unwrap()
     }
+
+    /// Serialize this signature in a self-delimiting tag-length-value frame:
+    /// the [`SIGNATURE_FRAME_TAG`] byte, the DER encoding of the length of the
+    /// serialized signature, and the serialized signature itself.
+    pub fn to_framed(&self) -> Vec<u8> {
+        let serialization = self.serialize();
+        let value = serialization.as_ref();
+        let len = value.len();
+
+        let mut bytes = vec![SIGNATURE_FRAME_TAG];
+        if len < 0x80 {
+            bytes.push(len as u8);
+        } else if len <= 0xff {
+            bytes.extend([0x81, len as u8]);
+        } else {
+            bytes.push(0x82);
+            bytes.extend((len as u16).to_be_bytes());
+        }
+        bytes.extend_from_slice(value);
+        bytes
+    }
+
+    /// Deserialize a signature from a frame produced by
+    /// [`Signature::to_framed`].
+    ///
+    /// Returns [`Error::MalformedSignature`] if the tag is wrong, or if the
+    /// length is not the ciphersuite's [`Ciphersuite::signature_len`] or does
+    /// not match the length of the value.
+    pub fn from_framed(bytes: &[u8]) -> Result<Self, Error<C>> {
+        let (tag, rest) = bytes.split_first().ok_or(Error::MalformedSignature)?;
+        if *tag != SIGNATURE_FRAME_TAG {
+            return Err(Error::MalformedSignature);
+        }
+
+        let (len, value) = match rest {
+            [len, value @ ..] if *len < 0x80 => (*len as usize, value),
+            [0x81, len, value @ ..] if *len >= 0x80 => (*len as usize, value),
+            [0x82, hi, lo, value @ ..] if *hi != 0 => {
+                (u16::from_be_bytes([*hi, *lo]) as usize, value)
+            }
+            _ => return Err(Error::MalformedSignature),
+        };
+        if len != C::signature_len() || value.len() != len {
+            return Err(Error::MalformedSignature);
+        }
+
+        Self::deserialize(
+            value
+                .to_vec()
+                .try_into()
+                .map_err(|_| Error::MalformedSignature)?,
+        )
+    }
+
+    /// Verify two signatures over the same `msg` under different keys, e.g.
+    /// those of two FROST groups attesting to the same message, returning the
+    /// verification result of each signature.
+    ///
+    /// Both signatures are first checked together with a single
+    /// [batch](crate::batch) verification, which is the only cost when both
+    /// are valid; otherwise each one is verified on its own to find which
+    /// failed.
+    pub fn verify_pair<R: RngCore + CryptoRng>(
+        msg: &[u8],
+        sig_a: &Signature<C>,
+        key_a: &VerifyingKey<C>,
+        sig_b: &Signature<C>,
+        key_b: &VerifyingKey<C>,
+        rng: &mut R,
+    ) -> (Result<(), Error<C>>, Result<(), Error<C>>) {
+        let mut verifier = batch::Verifier::new();
+        verifier.queue((*key_a, *sig_a, &msg));
+        verifier.queue((*key_b, *sig_b, &msg));
+
+        match verifier.verify(rng) {
+            Ok(()) => (Ok(()), Ok(())),
+            Err(_) => (key_a.verify(msg, sig_a), key_b.verify(msg, sig_b)),
+        }
+    }
 }
 
+/// The tag of a framed signature; see [`Signature::to_framed`]. This is the
+/// DER tag of an octet string.
+pub const SIGNATURE_FRAME_TAG: u8 = 0x04;
+
 #[cfg(feature = "serde")]
 impl<C> serde::Serialize for Signature<C>
 where
@@ -44,19 +44,33 @@ where
     }
 
     /// Create a signature `msg` using this `SigningKey`.
-    pub fn sign<R: RngCore + CryptoRng>(&self, mut rng: R, msg: &[u8]) -> Signature<C> {
-        let k = random_nonzero::<C, R>(&mut rng);
+    pub fn sign<R: RngCore + CryptoRng>(&self, rng: &mut R, msg: &[u8]) -> Signature<C> {
+        let k = random_nonzero::<C, R>(rng);
 
         let R = <C::Group>::generator() * k;
 
         // Generate Schnorr challenge
-        let c = crate::challenge::<C>(&R, &VerifyingKey::<C>::from(*self), msg);
+        let c = crate::challenge::<C>(&R, &self.verifying_key(), msg);
 
         let z = k + (c.0 * self.scalar);
 
         Signature { R, z }
     }
 
+    /// Return the [`VerifyingKey`] corresponding to this `SigningKey`, which
+    /// verifies the signatures created with [`SigningKey::sign`].
+    pub fn verifying_key(&self) -> VerifyingKey<C> {
+        VerifyingKey::from(*self)
+    }
+
+    /// Return true if `verifying_key` is the [`VerifyingKey`] corresponding to
+    /// this `SigningKey`, e.g. to check a key recovered with
+    /// [`keys::reconstruct`](crate::keys::reconstruct) against the group
+    /// verifying key before using it.
+    pub fn matches(&self, verifying_key: &VerifyingKey<C>) -> bool {
+        self.verifying_key() == *verifying_key
+    }
+
     /// Creates a SigningKey from a scalar.
     pub fn from_scalar(
         scalar: <<<C as Ciphersuite>::Group as Group>::Field as Field>::Scalar,
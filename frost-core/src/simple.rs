@@ -0,0 +1,84 @@
+//! One-shot signing when all the key packages are held locally.
+//!
+//! This is only meant for prototyping, tests and demos: holding all the
+//! signing shares in a single place defeats the purpose of threshold
+//! signatures, since whoever holds them can reconstruct the group signing
+//! key. Real deployments must run [`round1`](crate::round1) and
+//! [`round2`](crate::round2) on each participant's own device.
+
+use std::collections::BTreeMap;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    keys::{KeyPackage, PublicKeyPackage},
+    round1, round2, Ciphersuite, Error, Identifier, Signature, SigningPackage,
+};
+
+/// Run all the signing rounds locally, with every given key package as a
+/// signer, and return the aggregated [`Signature`] over `message`.
+///
+/// See the [module documentation](self) for why this must not be used in
+/// production.
+pub fn sign_all<C: Ciphersuite, R: RngCore + CryptoRng>(
+    message: &[u8],
+    key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    rng: &mut R,
+) -> Result<Signature<C>, Error<C>> {
+    let nonces = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            (
+                *identifier,
+                round1::SigningNonces::new(key_package.signing_share(), rng),
+            )
+        })
+        .collect();
+
+    sign_all_with_nonces_internal(message, key_packages, pubkeys, &nonces)
+}
+
+/// Like [`sign_all`], but uses the given nonces for each signer instead of
+/// generating them, so that the signature (including its `R`) is
+/// deterministic, e.g. for regression tests.
+///
+/// Reusing nonces to sign different messages leaks the signing shares, so
+/// this must never be used outside tests.
+#[cfg(feature = "test-vectors")]
+pub fn sign_all_with_nonces<C: Ciphersuite>(
+    message: &[u8],
+    key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    nonces: &BTreeMap<Identifier<C>, round1::SigningNonces<C>>,
+) -> Result<Signature<C>, Error<C>> {
+    sign_all_with_nonces_internal(message, key_packages, pubkeys, nonces)
+}
+
+fn sign_all_with_nonces_internal<C: Ciphersuite>(
+    message: &[u8],
+    key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    nonces: &BTreeMap<Identifier<C>, round1::SigningNonces<C>>,
+) -> Result<Signature<C>, Error<C>> {
+    let signing_commitments = key_packages
+        .keys()
+        .map(|identifier| {
+            let nonces = nonces.get(identifier).ok_or(Error::MissingCommitment)?;
+            Ok((*identifier, *nonces.commitments()))
+        })
+        .collect::<Result<BTreeMap<_, _>, Error<C>>>()?;
+
+    let signing_package = SigningPackage::new(signing_commitments, message);
+
+    let signature_shares = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            let nonces = nonces.get(identifier).ok_or(Error::MissingCommitment)?;
+            let signature_share = round2::sign(&signing_package, nonces, key_package)?;
+            Ok((*identifier, signature_share))
+        })
+        .collect::<Result<BTreeMap<_, _>, Error<C>>>()?;
+
+    crate::aggregate(&signing_package, &signature_shares, pubkeys)
+}
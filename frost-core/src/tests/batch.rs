@@ -12,7 +12,7 @@ pub fn batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
         assert!(vk.verify(msg, &sig).is_ok());
         batch.queue((vk, sig, msg));
     }
-    assert!(batch.verify(rng).is_ok());
+    assert!(batch.verify(&mut rng).is_ok());
 }
 
 /// Test failure case of batch verification with a Ciphersuite.
@@ -45,7 +45,7 @@ pub fn bad_batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
         items.push(item.clone());
         batch.queue(item);
     }
-    assert!(batch.verify(rng).is_err());
+    assert!(batch.verify(&mut rng).is_err());
     for (i, item) in items.drain(..).enumerate() {
         if i != bad_index {
             assert!(item.verify_single().is_ok());
@@ -57,7 +57,7 @@ pub fn bad_batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
 
 /// Test if the empty batch fails to validate.
 /// Test case from NCC audit.
-pub fn empty_batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(rng: R) {
+pub fn empty_batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
     let batch = batch::Verifier::<C>::new();
-    assert!(batch.verify(rng).is_err());
+    assert!(batch.verify(&mut rng).is_err());
 }
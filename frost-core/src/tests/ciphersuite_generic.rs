@@ -1,13 +1,19 @@
 //! Ciphersuite-generic test functions.
 #![allow(clippy::type_complexity)]
 
-use std::{collections::BTreeMap, convert::TryFrom};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+};
 
 use crate as frost;
 use crate::{
-    keys::PublicKeyPackage, Error, Field, Group, Identifier, Signature, SigningKey, VerifyingKey,
+    keys::PublicKeyPackage, Error, Field, FieldError, Group, GroupError, Identifier, Signature,
+    SigningKey, VerifyingKey,
 };
+use debugless_unwrap::DebuglessUnwrap;
 use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroize;
 
 use crate::Ciphersuite;
 
@@ -19,6 +25,41 @@ pub fn check_zero_key_fails<C: Ciphersuite>() {
     assert_eq!(r, Err(Error::MalformedSigningKey));
 }
 
+/// Test that the encoding of `order - 1` (the largest scalar) deserializes
+/// and that the encoding of `order` itself is rejected as non-canonical.
+pub fn check_deserialize_scalar_bounds<C: Ciphersuite>() {
+    let zero = <<<C as Ciphersuite>::Group as Group>::Field>::zero();
+    let one = <<<C as Ciphersuite>::Group as Group>::Field>::one();
+    let order_minus_one = zero - one;
+
+    let encoded = <<<C as Ciphersuite>::Group as Group>::Field>::serialize(&order_minus_one);
+    let r = <<<C as Ciphersuite>::Group as Group>::Field>::deserialize(&encoded);
+    assert!(r == Ok(order_minus_one));
+
+    // Compute the little-endian encoding of `order` by adding one to
+    // `order - 1`, propagating the carry.
+    let mut order_bytes =
+        <<<C as Ciphersuite>::Group as Group>::Field>::little_endian_serialize(&order_minus_one)
+            .as_ref()
+            .to_vec();
+    for byte in order_bytes.iter_mut() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+        if !carry {
+            break;
+        }
+    }
+    // Convert to the ciphersuite encoding, which may be big-endian.
+    if <<<C as Ciphersuite>::Group as Group>::Field>::serialize(&one).as_ref()
+        != <<<C as Ciphersuite>::Group as Group>::Field>::little_endian_serialize(&one).as_ref()
+    {
+        order_bytes.reverse();
+    }
+    let encoded_order = order_bytes.try_into().debugless_unwrap();
+    let r = <<<C as Ciphersuite>::Group as Group>::Field>::deserialize(&encoded_order);
+    assert!(r == Err(FieldError::MalformedScalar));
+}
+
 /// Test share generation with a Ciphersuite
 pub fn check_share_generation<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
     let secret = crate::SigningKey::<C>::new(&mut rng);
@@ -52,8 +93,16 @@ pub fn check_share_generation<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R
         secret.serialize().as_ref()
     );
 
+    assert!(frost::keys::can_reconstruct::<C>(&key_packages, min_signers).is_ok());
+    assert!(frost::keys::can_reconstruct::<C>(&key_packages[0..3], min_signers).is_ok());
+
     // Test error cases
 
+    assert_eq!(
+        frost::keys::can_reconstruct::<C>(&key_packages[0..2], min_signers).unwrap_err(),
+        Error::IncorrectNumberOfShares
+    );
+
     assert_eq!(
         frost::keys::reconstruct::<C>(&[]).unwrap_err(),
         Error::IncorrectNumberOfShares
@@ -67,6 +116,11 @@ pub fn check_share_generation<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R
     let mut key_packages = key_packages;
     key_packages[0] = key_packages[1].clone();
 
+    assert_eq!(
+        frost::keys::can_reconstruct::<C>(&key_packages, min_signers).unwrap_err(),
+        Error::DuplicatedIdentifier
+    );
+
     assert_eq!(
         frost::keys::reconstruct::<C>(&key_packages).unwrap_err(),
         Error::DuplicatedIdentifier
@@ -129,7 +183,10 @@ pub fn check_sign_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
     // if the number of shares is at least `min_signers`. To bypass the check
     // and test if the protocol itself fails with not enough signers, we modify
     // the `KeyPackages`s, decrementing their saved `min_signers` value before
-    // running the signing procedure.
+    // running the signing procedure. Likewise for the `PublicKeyPackage`, since
+    // aggregation checks the number of shares too.
+    let mut lowered_pubkeys = pubkeys.clone();
+    lowered_pubkeys.min_signers = lowered_pubkeys.min_signers.map(|n| n - 1);
     let r = check_sign(
         min_signers - 1,
         key_packages
@@ -143,13 +200,278 @@ pub fn check_sign_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
             })
             .collect(),
         &mut rng,
-        pubkeys.clone(),
+        lowered_pubkeys,
     );
     assert_eq!(r, Err(Error::InvalidSignature));
 
     check_sign(min_signers, key_packages, rng, pubkeys).unwrap()
 }
 
+/// Test that splitting a key with the same seed is reproducible, and that
+/// different seeds produce different shares.
+pub fn check_split_deterministic<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let key = SigningKey::<C>::new(&mut rng);
+    let max_signers = 5;
+    let min_signers = 3;
+
+    let split = |seed: [u8; 32]| {
+        frost::keys::split_deterministic(
+            &key,
+            max_signers,
+            min_signers,
+            frost::keys::IdentifierList::Default,
+            seed,
+        )
+        .unwrap()
+    };
+
+    let (shares1, pubkeys1) = split([1u8; 32]);
+    let (shares2, pubkeys2) = split([1u8; 32]);
+    let (shares3, pubkeys3) = split([2u8; 32]);
+
+    assert_eq!(shares1, shares2);
+    assert_eq!(pubkeys1, pubkeys2);
+    for (share1, share2) in shares1.values().zip(shares2.values()) {
+        assert_eq!(
+            share1.signing_share().serialize().as_ref(),
+            share2.signing_share().serialize().as_ref()
+        );
+    }
+
+    assert_ne!(shares1, shares3);
+    // The group key does not depend on the seed.
+    assert_eq!(pubkeys1.verifying_key(), pubkeys3.verifying_key());
+    assert_ne!(pubkeys1.verifying_shares(), pubkeys3.verifying_shares());
+}
+
+/// Test signing with commitments drawn from a [`frost::round1::CommitmentPool`].
+pub fn check_sign_with_commitment_pool<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    // Each participant preprocesses two nonces and publishes the commitments.
+    let mut pool = frost::round1::CommitmentPool::new();
+    let mut nonces_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) =
+            frost::round1::preprocess(2, key_package.signing_share(), &mut rng);
+        pool.add(*id, commitments);
+        nonces_map.insert(*id, nonces);
+    }
+
+    // A commitment can't be taken twice.
+    let id = *key_packages.keys().next().unwrap();
+    let first = pool.take_one(&id).unwrap();
+    let second = pool.take_one(&id).unwrap();
+    assert_ne!(first, second);
+    assert_eq!(pool.take_one(&id), None);
+    assert_eq!(pool.available(&id), 0);
+
+    // The first signer has no commitments left.
+    let signers: std::collections::BTreeSet<_> = key_packages
+        .keys()
+        .take(min_signers as usize)
+        .copied()
+        .collect();
+    assert_eq!(
+        pool.signing_package(&signers, b"message").unwrap_err(),
+        Error::MissingCommitment
+    );
+    // A failed attempt must not consume commitments.
+    assert!(signers.iter().skip(1).all(|id| pool.available(id) == 2));
+
+    let signers: std::collections::BTreeSet<_> = key_packages
+        .keys()
+        .skip(1)
+        .take(min_signers as usize)
+        .copied()
+        .collect();
+    let message = b"message to sign";
+    let signing_package = pool.signing_package(&signers, message).unwrap();
+    assert!(signers.iter().all(|id| pool.available(id) == 1));
+
+    let mut signature_shares = BTreeMap::new();
+    for id in &signers {
+        let commitment = signing_package.signing_commitment(id).unwrap();
+        let nonces = nonces_map[id]
+            .iter()
+            .find(|nonces| *nonces.commitments() == commitment)
+            .unwrap();
+        let signature_share =
+            frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+        signature_shares.insert(*id, signature_share);
+    }
+
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
+
+/// Test [`PublicKeyPackage::audit`] with valid and corrupted packages.
+pub fn check_public_key_package_audit<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (_, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    pubkeys.audit(min_signers).unwrap();
+    assert_eq!(
+        pubkeys.audit(max_signers + 1).unwrap_err(),
+        Error::InvalidMinSigners
+    );
+
+    let ids: Vec<_> = pubkeys.verifying_shares().keys().copied().collect();
+
+    // Duplicated share
+    let mut verifying_shares = pubkeys.verifying_shares().clone();
+    verifying_shares.insert(ids[1], verifying_shares[&ids[0]]);
+    let corrupted = PublicKeyPackage::new(verifying_shares, *pubkeys.verifying_key());
+    assert_eq!(
+        corrupted.audit(min_signers).unwrap_err(),
+        Error::DuplicatedShares
+    );
+
+    // Identity share
+    let mut verifying_shares = pubkeys.verifying_shares().clone();
+    verifying_shares.insert(
+        ids[4],
+        frost::keys::VerifyingShare(<C::Group as Group>::identity()),
+    );
+    let corrupted = PublicKeyPackage::new(verifying_shares, *pubkeys.verifying_key());
+    assert_eq!(
+        corrupted.audit(min_signers).unwrap_err(),
+        Error::GroupError(GroupError::InvalidIdentityElement)
+    );
+
+    // Inconsistent group key
+    let other_key = VerifyingKey::from(SigningKey::<C>::new(&mut rng));
+    let corrupted = PublicKeyPackage::new(pubkeys.verifying_shares().clone(), other_key);
+    assert_eq!(
+        corrupted.audit(min_signers).unwrap_err(),
+        Error::InconsistentPublicKeyPackage
+    );
+
+    // Share that does not lie on the polynomial
+    let mut verifying_shares = pubkeys.verifying_shares().clone();
+    let other_share = frost::keys::VerifyingShare(other_key.element);
+    verifying_shares.insert(ids[4], other_share);
+    let corrupted = PublicKeyPackage::new(verifying_shares, *pubkeys.verifying_key());
+    assert_eq!(
+        corrupted.audit(min_signers).unwrap_err(),
+        Error::InconsistentPublicKeyPackage
+    );
+}
+
+/// Test that signature shares bundled with their verification data are
+/// verified against the signing package, and that tampered or forged bundles
+/// are rejected.
+pub fn check_signature_share_with_proof<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    let message = b"message to sign";
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    let verifying_key = pubkeys.verifying_key();
+
+    for (id, key_package) in &key_packages {
+        let share = frost::round2::sign(&signing_package, &nonces_map[id], key_package).unwrap();
+        let bundle =
+            frost::round2::SignatureShare::with_proof(&signing_package, verifying_key, *id, share)
+                .unwrap();
+        let verifying_share = &pubkeys.verifying_shares()[id];
+        bundle
+            .verify_with_signing_package(&signing_package, verifying_share, verifying_key)
+            .unwrap();
+
+        // A bundle made for another message must be rejected.
+        let mut tampered = bundle.clone();
+        tampered.message_digest = C::H4(b"another message").as_ref().to_vec();
+        assert_eq!(
+            tampered.verify_with_signing_package(&signing_package, verifying_share, verifying_key),
+            Err(Error::MessageDigestMismatch)
+        );
+
+        // So must a tampered R_share.
+        let mut tampered = bundle.clone();
+        tampered.commitment_share = tampered.commitment_share + C::Group::generator();
+        assert_eq!(
+            tampered.verify_with_signing_package(&signing_package, verifying_share, verifying_key),
+            Err(Error::IncorrectCommitment)
+        );
+
+        // So must a tampered set of signers, which changes lambda_i.
+        let mut tampered = bundle.clone();
+        tampered
+            .signers
+            .insert(*pubkeys.verifying_shares().keys().last().unwrap());
+        assert_eq!(
+            tampered.verify_with_signing_package(&signing_package, verifying_share, verifying_key),
+            Err(Error::IncorrectCommitment)
+        );
+
+        // So must a share checked against another signer's verifying share.
+        let other = key_packages.keys().find(|other| *other != id).unwrap();
+        assert_eq!(
+            bundle.verify_with_signing_package(
+                &signing_package,
+                &pubkeys.verifying_shares()[other],
+                verifying_key
+            ),
+            Err(Error::InvalidSignatureShare { culprit: *id })
+        );
+
+        // A relay choosing R_share can make an arbitrary share consistent
+        // with the bundled values, but not with the signing package.
+        let lambda_i = frost::compute_lagrange_coefficient(&bundle.signers, None, *id).unwrap();
+        let challenge = crate::challenge::<C>(&bundle.group_commitment, verifying_key, message);
+        let mut forged = bundle.clone();
+        forged.share.share = <<C::Group as Group>::Field>::random(&mut rng);
+        forged.commitment_share =
+            C::Group::generator() * forged.share.share - verifying_share.0 * challenge.0 * lambda_i;
+        forged
+            .verify_with_proof(verifying_share, verifying_key, message)
+            .unwrap();
+        assert_eq!(
+            forged.verify_with_signing_package(&signing_package, verifying_share, verifying_key),
+            Err(Error::IncorrectCommitment)
+        );
+    }
+}
+
 /// Test FROST signing with trusted dealer fails with invalid numbers of signers.
 pub fn check_sign_with_dealer_fails_with_invalid_signers<C: Ciphersuite, R: RngCore + CryptoRng>(
     min_signers: u16,
@@ -253,6 +575,7 @@ pub fn check_sign<C: Ciphersuite + PartialEq, R: RngCore + CryptoRng>(
         header: pubkey_package.header,
         verifying_shares: BTreeMap::new(),
         verifying_key: pubkey_package.verifying_key,
+        min_signers: pubkey_package.min_signers,
     };
 
     check_aggregate_errors(
@@ -634,6 +957,86 @@ pub fn check_sign_with_dealer_and_identifiers<C: Ciphersuite, R: RngCore + Crypt
     check_sign(min_signers, key_packages, rng, pubkeys).unwrap()
 }
 
+/// Test splitting with sparse, non-contiguous identifiers and signing with
+/// every possible subset of signers.
+pub fn check_sign_with_sparse_identifiers<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use itertools::Itertools;
+
+    let identifiers: Vec<frost::Identifier<C>> = [7u16, 42, 1000]
+        .into_iter()
+        .map(|i| i.try_into().unwrap())
+        .collect();
+
+    let max_signers = 3;
+    let min_signers = 2;
+    let key = SigningKey::new(&mut rng);
+    let (shares, pubkeys) = frost::keys::split(
+        &key,
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Custom(&identifiers),
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        shares.keys().copied().collect::<Vec<_>>(),
+        identifiers,
+        "the specified identifiers must be used"
+    );
+    assert!(pubkeys.audit(min_signers).is_ok());
+
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    // Verifying shares must be derived from the share at the given identifier.
+    for (id, key_package) in &key_packages {
+        assert_eq!(
+            frost::keys::VerifyingShare::from(*key_package.signing_share()),
+            pubkeys.verifying_shares()[id]
+        );
+    }
+
+    let message = b"message to sign";
+    for signers in key_packages.values().combinations(min_signers as usize) {
+        let signers: Vec<_> = signers.into_iter().cloned().collect();
+
+        // Lagrange interpolation must recover the key from any subset.
+        assert_eq!(
+            frost::keys::reconstruct(&signers)
+                .unwrap()
+                .serialize()
+                .as_ref(),
+            key.serialize().as_ref()
+        );
+
+        let mut nonces_map = BTreeMap::new();
+        let mut commitments_map = BTreeMap::new();
+        for key_package in &signers {
+            let (nonces, commitments) =
+                frost::round1::commit(key_package.signing_share(), &mut rng);
+            nonces_map.insert(*key_package.identifier(), nonces);
+            commitments_map.insert(*key_package.identifier(), commitments);
+        }
+        let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+        let signature_shares: BTreeMap<_, _> = signers
+            .iter()
+            .map(|key_package| {
+                let id = *key_package.identifier();
+                let share =
+                    frost::round2::sign(&signing_package, &nonces_map[&id], key_package).unwrap();
+                (id, share)
+            })
+            .collect();
+
+        let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+        assert!(pubkeys.verifying_key().verify(message, &signature).is_ok());
+        assert!(VerifyingKey::from(&key).verify(message, &signature).is_ok());
+    }
+}
+
 fn check_part2_error<C: Ciphersuite>(
     round1_secret_package: frost::keys::dkg::round1::SecretPackage<C>,
     mut round1_packages: BTreeMap<frost::Identifier<C>, frost::keys::dkg::round1::Package<C>>,
@@ -826,3 +1229,3066 @@ pub fn check_sign_with_incorrect_commitments<C: Ciphersuite, R: RngCore + Crypto
     assert!(signature_share.is_err());
     assert!(signature_share == Err(Error::IncorrectCommitment))
 }
+
+/// Test that additional entropy passed to `commit_with_aux` is mixed into the
+/// nonces.
+pub fn check_commit_with_aux<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    let signing_share =
+        frost::keys::SigningShare::<C>::new(<<C::Group as Group>::Field>::random(&mut rng));
+    let mut seed = [0u8; 32];
+    rng.fill_bytes(&mut seed);
+
+    let (nonces_a, commitments_a) =
+        frost::round1::commit_with_aux(&signing_share, b"aux a", &mut ChaCha20Rng::from_seed(seed));
+    let (nonces_b, commitments_b) =
+        frost::round1::commit_with_aux(&signing_share, b"aux b", &mut ChaCha20Rng::from_seed(seed));
+    assert_ne!(nonces_a, nonces_b);
+    assert_ne!(commitments_a, commitments_b);
+
+    // The same RNG seed and aux yield the same nonces.
+    let (nonces_a2, _) =
+        frost::round1::commit_with_aux(&signing_share, b"aux a", &mut ChaCha20Rng::from_seed(seed));
+    assert_eq!(nonces_a, nonces_a2);
+
+    // An empty aux matches plain `commit`.
+    let (nonces_empty, _) =
+        frost::round1::commit_with_aux(&signing_share, &[], &mut ChaCha20Rng::from_seed(seed));
+    let (nonces, _) = frost::round1::commit(&signing_share, &mut ChaCha20Rng::from_seed(seed));
+    assert_eq!(nonces_empty, nonces);
+}
+
+/// Test collapsing a 2-of-2 key into a single signing key.
+pub fn check_collapse_to_single<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(2, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: Vec<frost::keys::KeyPackage<C>> = shares
+        .into_values()
+        .map(|share| share.try_into().unwrap())
+        .collect();
+
+    // All shares are required, even though that exceeds min_signers in general.
+    assert_eq!(
+        frost::keys::collapse_to_single(&key_packages[..1], &pubkeys).unwrap_err(),
+        Error::IncorrectNumberOfShares
+    );
+
+    let signing_key = frost::keys::collapse_to_single(&key_packages, &pubkeys).unwrap();
+    let message = b"message to sign";
+    let signature = signing_key.sign(&mut rng, message);
+    assert!(pubkeys.verifying_key().verify(message, &signature).is_ok());
+
+    // Packages from a different key are rejected.
+    let (other_shares, _) =
+        frost::keys::generate_with_dealer(2, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let other_key_packages: Vec<frost::keys::KeyPackage<C>> = other_shares
+        .into_values()
+        .map(|share| share.try_into().unwrap())
+        .collect();
+    assert_eq!(
+        frost::keys::collapse_to_single(&other_key_packages, &pubkeys).unwrap_err(),
+        Error::InvalidSecretShare
+    );
+}
+
+/// Test that group commitment shares computed with the public API match the
+/// group commitment used by `aggregate`.
+pub fn check_to_group_commitment_share<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    let mut signature_shares = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let share = frost::round2::sign(&signing_package, &nonces_map[id], key_package).unwrap();
+        signature_shares.insert(*id, share);
+    }
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    // Compute each R_share from a binding factor derived with the public API;
+    // their sum must be the group commitment R used in the signature.
+    let mut group_commitment = C::Group::identity();
+    for (id, preimage) in signing_package.binding_factor_preimages(pubkeys.verifying_key(), &[]) {
+        let binding_factor = crate::BindingFactor::<C>::new(C::H1(&preimage));
+        let share = signing_package
+            .signing_commitment(&id)
+            .unwrap()
+            .to_group_commitment_share(&binding_factor);
+        group_commitment = group_commitment + share.to_element();
+    }
+    assert!(group_commitment == signature.R);
+}
+
+/// Test verifying individual signature shares with `round2::check_share`.
+pub fn check_check_share<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    for (id, key_package) in &key_packages {
+        let share = frost::round2::sign(&signing_package, &nonces_map[id], key_package).unwrap();
+        assert!(frost::round2::check_share(
+            &share,
+            *id,
+            &signing_package,
+            &pubkeys.verifying_shares()[id],
+            pubkeys.verifying_key(),
+        )
+        .is_ok());
+
+        let one = <<C as Ciphersuite>::Group as Group>::Field::one();
+        let invalid_share = frost::round2::SignatureShare {
+            share: share.share + one,
+        };
+        assert_eq!(
+            frost::round2::check_share(
+                &invalid_share,
+                *id,
+                &signing_package,
+                &pubkeys.verifying_shares()[id],
+                pubkeys.verifying_key(),
+            ),
+            Err(Error::InvalidSignatureShare { culprit: *id })
+        );
+    }
+}
+
+/// Test converting all dealer-generated shares into key packages at once.
+pub fn check_key_packages<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (mut shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages = frost::keys::key_packages(&shares).unwrap();
+    assert_eq!(key_packages.len(), max_signers as usize);
+    for (id, key_package) in &key_packages {
+        assert_eq!(key_package.identifier(), id);
+        assert_eq!(
+            key_package.verifying_share(),
+            &pubkeys.verifying_shares()[id]
+        );
+        assert_eq!(key_package.verifying_key(), pubkeys.verifying_key());
+    }
+
+    // Corrupt one share
+    let id = *shares.keys().nth(1).unwrap();
+    let one = <<C as Ciphersuite>::Group as Group>::Field::one();
+    let share = shares.get_mut(&id).unwrap();
+    share.signing_share = frost::keys::SigningShare::new(share.signing_share.to_scalar() + one);
+
+    let err = frost::keys::key_packages(&shares).unwrap_err();
+    assert_eq!(err, Error::InvalidParticipantSecretShare { culprit: id });
+    assert_eq!(err.culprit(), Some(id));
+}
+
+/// Test that the aggregate signature's `z` is the sum of the serialized
+/// signature shares.
+pub fn check_aggregate_z_is_sum_of_shares<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(min_signers as usize) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    let mut signature_shares = BTreeMap::new();
+    for (id, nonces) in &nonces_map {
+        let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+        signature_shares.insert(*id, share);
+    }
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    // Reconstruct z as an external verifier would, from the share bytes only.
+    let mut z = <<C::Group as Group>::Field>::zero();
+    for share in signature_shares.values() {
+        let share = frost::round2::SignatureShare::<C>::deserialize(share.serialize()).unwrap();
+        z = z + share.share;
+    }
+    assert!(z == signature.z);
+
+    let z_bytes = <<C::Group as Group>::Field>::serialize(&z);
+    assert!(signature.serialize().as_ref().ends_with(z_bytes.as_ref()));
+}
+
+/// Test that signers refuse to sign a message that doesn't match the agreed
+/// message digest.
+pub fn check_sign_with_message_digest<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(min_signers as usize) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let mut ids = nonces_map.keys().copied();
+    let (id_a, id_b) = (ids.next().unwrap(), ids.next().unwrap());
+
+    // The coordinator sends a different message to each signer.
+    let signing_package_a = frost::SigningPackage::new(commitments_map.clone(), b"message A");
+    let signing_package_b = frost::SigningPackage::new(commitments_map.clone(), b"message B");
+    assert_ne!(
+        signing_package_a.message_digest().as_ref(),
+        signing_package_b.message_digest().as_ref()
+    );
+
+    // The signers agree on the digest of what A received; B detects the mismatch.
+    let agreed_digest = signing_package_a.message_digest();
+    assert_eq!(
+        frost::round2::sign_with_message_digest(
+            &signing_package_b,
+            &nonces_map[&id_b],
+            &key_packages[&id_b],
+            agreed_digest.as_ref(),
+        )
+        .unwrap_err(),
+        Error::MessageDigestMismatch
+    );
+
+    // With consistent messages, signing and aggregation succeed.
+    let signature_shares: BTreeMap<_, _> = [id_a, id_b]
+        .into_iter()
+        .map(|id| {
+            let share = frost::round2::sign_with_message_digest(
+                &signing_package_a,
+                &nonces_map[&id],
+                &key_packages[&id],
+                agreed_digest.as_ref(),
+            )
+            .unwrap();
+            (id, share)
+        })
+        .collect();
+    let signature = frost::aggregate(&signing_package_a, &signature_shares, &pubkeys).unwrap();
+    assert!(pubkeys
+        .verifying_key()
+        .verify(b"message A", &signature)
+        .is_ok());
+}
+
+/// Test that `aggregate_commitment` recomputes the signature's `R`.
+pub fn check_aggregate_commitment<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(min_signers as usize) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    let mut signature_shares = BTreeMap::new();
+    for (id, nonces) in &nonces_map {
+        let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+        signature_shares.insert(*id, share);
+    }
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    let R = frost::aggregate_commitment(&signing_package, pubkeys.verifying_key()).unwrap();
+    assert!(R == signature.R);
+
+    // A different message yields a different commitment.
+    let other_package = frost::SigningPackage::new(
+        signing_package.signing_commitments().clone(),
+        b"another message",
+    );
+    let other_R = frost::aggregate_commitment(&other_package, pubkeys.verifying_key()).unwrap();
+    assert!(other_R != signature.R);
+}
+
+/// Test that signing sets exceeding the signer limit are rejected.
+pub fn check_signer_limit<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(min_signers as usize) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let message = b"message to sign";
+
+    assert_eq!(
+        frost::SigningPackage::new_with_signer_limit(commitments_map.clone(), message, 2)
+            .unwrap_err(),
+        Error::TooManySigners
+    );
+    let signing_package =
+        frost::SigningPackage::new_with_signer_limit(commitments_map, message, 3).unwrap();
+
+    let mut signature_shares = BTreeMap::new();
+    for (id, nonces) in &nonces_map {
+        let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+        signature_shares.insert(*id, share);
+    }
+
+    assert_eq!(
+        frost::aggregate_with_signer_limit(&signing_package, &signature_shares, &pubkeys, 2)
+            .unwrap_err(),
+        Error::TooManySigners
+    );
+    assert!(
+        frost::aggregate_with_signer_limit(&signing_package, &signature_shares, &pubkeys, 3)
+            .is_ok()
+    );
+    assert!(frost::aggregate(&signing_package, &signature_shares, &pubkeys).is_ok());
+
+    // `aggregate` and deserialization reject more than `DEFAULT_SIGNER_LIMIT`
+    // signers by default. The same commitments and share are reused for every
+    // identifier, since only the number of entries matters.
+    let commitments = *signing_package
+        .signing_commitments()
+        .values()
+        .next()
+        .unwrap();
+    let share = *signature_shares.values().next().unwrap();
+    let identifiers: Vec<Identifier<C>> = (1..=frost::DEFAULT_SIGNER_LIMIT as u16 + 1)
+        .map(|i| i.try_into().unwrap())
+        .collect();
+    let commitments_map: BTreeMap<_, _> = identifiers.iter().map(|id| (*id, commitments)).collect();
+    let signature_shares: BTreeMap<_, _> = identifiers.iter().map(|id| (*id, share)).collect();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    assert_eq!(
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap_err(),
+        Error::TooManySigners
+    );
+    #[cfg(feature = "serialization")]
+    {
+        let bytes = signing_package.serialize().unwrap();
+        assert_eq!(
+            frost::SigningPackage::<C>::deserialize(&bytes).unwrap_err(),
+            Error::DeserializationError
+        );
+    }
+}
+
+/// A mock signer backend that records the operations it's asked to perform.
+struct RecordingBackend<C: Ciphersuite> {
+    inner: frost::round2::SoftwareBackend<C>,
+    operations: std::cell::RefCell<Vec<&'static str>>,
+}
+
+impl<C: Ciphersuite> frost::round2::SignerBackend<C> for RecordingBackend<C> {
+    fn scalar_mul_base(&self) -> Result<frost::keys::VerifyingShare<C>, Error<C>> {
+        self.operations.borrow_mut().push("scalar_mul_base");
+        self.inner.scalar_mul_base()
+    }
+
+    fn scalar_mul(&self, scalar: &crate::Scalar<C>) -> Result<crate::Scalar<C>, Error<C>> {
+        self.operations.borrow_mut().push("scalar_mul");
+        self.inner.scalar_mul(scalar)
+    }
+}
+
+/// Test signing through a `SignerBackend`.
+pub fn check_sign_with_backend<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(min_signers as usize) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let message = b"message to sign";
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (id, nonces) in &nonces_map {
+        let key_package = &key_packages[id];
+        let backend = RecordingBackend {
+            inner: frost::round2::SoftwareBackend::new(*key_package.signing_share()),
+            operations: Default::default(),
+        };
+
+        let share = frost::round2::sign_with_backend(
+            &signing_package,
+            nonces,
+            &key_package.public_part(),
+            &backend,
+        )
+        .unwrap();
+        assert_eq!(
+            *backend.operations.borrow(),
+            vec!["scalar_mul_base", "scalar_mul"]
+        );
+        assert_eq!(
+            share,
+            frost::round2::sign(&signing_package, nonces, key_package).unwrap()
+        );
+        signature_shares.insert(*id, share);
+    }
+
+    // A backend holding another participant's share must be rejected before
+    // it is asked to sign.
+    let mut ids = nonces_map.keys();
+    let (id1, id2) = (*ids.next().unwrap(), *ids.next().unwrap());
+    let backend = RecordingBackend {
+        inner: frost::round2::SoftwareBackend::new(*key_packages[&id2].signing_share()),
+        operations: Default::default(),
+    };
+    assert_eq!(
+        frost::round2::sign_with_backend(
+            &signing_package,
+            &nonces_map[&id1],
+            &key_packages[&id1].public_part(),
+            &backend,
+        ),
+        Err(Error::SignerBackendMismatch)
+    );
+    assert_eq!(*backend.operations.borrow(), vec!["scalar_mul_base"]);
+
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert!(pubkeys.verifying_key().verify(message, &signature).is_ok());
+}
+
+/// Test computing and verifying proofs of possession of the shares.
+pub fn check_proof_of_possession<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages = frost::keys::key_packages(&shares).unwrap();
+
+    // The coordinator verifies every proof before accepting the participants.
+    let proofs: BTreeMap<_, _> = key_packages
+        .iter()
+        .map(|(id, key_package)| (*id, frost::keys::proof_of_possession(key_package, &mut rng)))
+        .collect();
+    for (id, proof) in &proofs {
+        frost::keys::verify_proof_of_possession(&pubkeys.verifying_shares()[id], *id, proof)
+            .unwrap();
+    }
+
+    // A proof for a different share must be rejected.
+    let mut ids = proofs.keys();
+    let (id1, id2) = (*ids.next().unwrap(), *ids.next().unwrap());
+    let err = frost::keys::verify_proof_of_possession(
+        &pubkeys.verifying_shares()[&id2],
+        id2,
+        &proofs[&id1],
+    )
+    .unwrap_err();
+    assert_eq!(err, Error::InvalidProofOfPossession { culprit: id2 });
+    assert_eq!(err.culprit(), Some(id2));
+
+    // A proof is bound to the identifier it was computed for.
+    assert!(frost::keys::verify_proof_of_possession(
+        &pubkeys.verifying_shares()[&id1],
+        id2,
+        &proofs[&id1],
+    )
+    .is_err());
+}
+
+/// Test that degenerate signatures are explicitly rejected.
+pub fn check_verify_rejects_degenerate_signatures<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let verifying_key = VerifyingKey::<C>::from(&signing_key);
+    let message = b"message to sign";
+    let signature = signing_key.sign(&mut rng, message);
+    assert!(verifying_key.verify(message, &signature).is_ok());
+
+    let identity_commitment = Signature::<C> {
+        R: <C::Group>::identity(),
+        z: signature.z,
+    };
+    assert_eq!(
+        verifying_key.verify(message, &identity_commitment),
+        Err(Error::IdentitySignatureCommitment)
+    );
+
+    let zero_response = Signature::<C> {
+        R: signature.R,
+        z: <<C::Group as Group>::Field>::zero(),
+    };
+    assert_eq!(
+        verifying_key.verify(message, &zero_response),
+        Err(Error::ZeroSignatureResponse)
+    );
+
+    // A zero response is structurally valid and survives deserialization, so
+    // it must be caught at verification time.
+    let zero_response = Signature::<C>::deserialize(zero_response.serialize()).unwrap();
+    assert_eq!(
+        verifying_key.verify(message, &zero_response),
+        Err(Error::ZeroSignatureResponse)
+    );
+
+    let mut batch = frost::batch::Verifier::<C>::new();
+    batch.queue((verifying_key, signature, message));
+    batch.queue((verifying_key, identity_commitment, message));
+    assert_eq!(
+        batch.verify(&mut rng),
+        Err(Error::IdentitySignatureCommitment)
+    );
+}
+
+/// Test signing one message under two groups with a `MultiGroupSession`.
+pub fn check_multi_group_session<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let message = b"message to sign";
+
+    let mut key_packages = BTreeMap::new();
+    let mut pubkey_packages = BTreeMap::new();
+    for group_id in ["custody-a", "custody-b"] {
+        let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+            max_signers,
+            min_signers,
+            frost::keys::IdentifierList::Default,
+            &mut rng,
+        )
+        .unwrap();
+        key_packages.insert(group_id, frost::keys::key_packages(&shares).unwrap());
+        pubkey_packages.insert(group_id, pubkeys);
+    }
+    assert_ne!(
+        pubkey_packages["custody-a"].verifying_key(),
+        pubkey_packages["custody-b"].verifying_key()
+    );
+
+    // Round 1, for every group.
+    let signers: Vec<Identifier<C>> = (1..=min_signers).map(|i| i.try_into().unwrap()).collect();
+    let mut nonces = BTreeMap::new();
+    let mut signing_packages = BTreeMap::new();
+    for (group_id, group_key_packages) in &key_packages {
+        let mut commitments = BTreeMap::new();
+        for id in &signers {
+            let (signing_nonces, signing_commitments) =
+                frost::round1::commit(group_key_packages[id].signing_share(), &mut rng);
+            nonces.insert((*group_id, *id), signing_nonces);
+            commitments.insert(*id, signing_commitments);
+        }
+        signing_packages.insert(*group_id, frost::SigningPackage::new(commitments, message));
+    }
+
+    // Round 2: each participant signs for all groups in one call.
+    let mut signature_shares: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+    for id in &signers {
+        let mut session = frost::multi_group::MultiGroupSession::<C, _>::new();
+        for (group_id, group_key_packages) in &key_packages {
+            session.insert(
+                *group_id,
+                group_key_packages[id].clone(),
+                nonces[&(*group_id, *id)].clone(),
+                signing_packages[group_id].clone(),
+            );
+        }
+        assert_eq!(session.len(), 2);
+
+        for (group_id, share) in session.sign().unwrap() {
+            signature_shares
+                .entry(group_id)
+                .or_default()
+                .insert(*id, share);
+        }
+    }
+
+    let signatures = frost::multi_group::aggregate_groups(
+        &signing_packages,
+        &signature_shares,
+        &pubkey_packages,
+    )
+    .unwrap();
+    assert_eq!(signatures.len(), 2);
+    for (group_id, signature) in &signatures {
+        pubkey_packages[group_id]
+            .verifying_key()
+            .verify(message, signature)
+            .unwrap();
+    }
+    assert!(pubkey_packages["custody-a"]
+        .verifying_key()
+        .verify(message, &signatures["custody-b"])
+        .is_err());
+
+    // A group without signature shares cannot be aggregated.
+    signature_shares.remove("custody-b");
+    assert_eq!(
+        frost::multi_group::aggregate_groups(
+            &signing_packages,
+            &signature_shares,
+            &pubkey_packages
+        ),
+        Err(Error::PackageNotFound)
+    );
+}
+
+/// Test the validation of custom identifier lists when splitting a key.
+pub fn check_split_with_custom_identifier_list<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let key = SigningKey::<C>::new(&mut rng);
+    let identifiers: Vec<Identifier<C>> = [3u16, 1, 2]
+        .into_iter()
+        .map(|i| i.try_into().unwrap())
+        .collect();
+
+    // Order is irrelevant: shares are keyed by identifier.
+    let mut seed = [0u8; 32];
+    rng.fill_bytes(&mut seed);
+    let mut sorted = identifiers.clone();
+    sorted.sort();
+    let (shares, pubkeys) = frost::keys::split_deterministic(
+        &key,
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Custom(&identifiers),
+        seed,
+    )
+    .unwrap();
+    let (sorted_shares, sorted_pubkeys) = frost::keys::split_deterministic(
+        &key,
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Custom(&sorted),
+        seed,
+    )
+    .unwrap();
+    assert_eq!(shares, sorted_shares);
+    assert_eq!(pubkeys, sorted_pubkeys);
+
+    // Duplicates are rejected.
+    let duplicated = [identifiers[0], identifiers[1], identifiers[0]];
+    assert_eq!(
+        frost::keys::split(
+            &key,
+            max_signers,
+            min_signers,
+            frost::keys::IdentifierList::Custom(&duplicated),
+            &mut rng,
+        )
+        .unwrap_err(),
+        Error::DuplicatedIdentifier
+    );
+
+    // The zero identifier can't be constructed, so it can't be in the list.
+    assert_eq!(
+        Identifier::<C>::try_from(0u16),
+        Err(Error::FieldError(FieldError::InvalidZeroScalar))
+    );
+    let zero = <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero());
+    assert_eq!(
+        Identifier::<C>::deserialize(&zero),
+        Err(Error::FieldError(FieldError::InvalidZeroScalar))
+    );
+}
+
+/// Test that signing packages built from the same commitments in different
+/// orders are equal and serialize identically.
+pub fn check_signing_package_equality_ignores_order<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 3;
+    let min_signers = 3;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let commitments: Vec<_> = shares
+        .iter()
+        .map(|(id, share)| {
+            (
+                *id,
+                frost::round1::commit(share.signing_share(), &mut rng).1,
+            )
+        })
+        .collect();
+    let message = b"message to sign";
+
+    let forward = frost::SigningPackage::new(commitments.iter().cloned().collect(), message);
+    let backward = frost::SigningPackage::new(commitments.iter().rev().cloned().collect(), message);
+    assert_eq!(forward, backward);
+    #[cfg(feature = "serialization")]
+    assert_eq!(forward.serialize().unwrap(), backward.serialize().unwrap());
+
+    let other_message = frost::SigningPackage::new(commitments.iter().cloned().collect(), b"other");
+    assert_ne!(forward, other_message);
+    let fewer_commitments =
+        frost::SigningPackage::new(commitments.iter().skip(1).cloned().collect(), message);
+    assert_ne!(forward, fewer_commitments);
+}
+
+/// Test converting raw bytes into an `Identifier`.
+pub fn check_identifier_try_from_bytes<C: Ciphersuite>() {
+    for n in [1u16, 2, 42, 1000, u16::MAX] {
+        let identifier = Identifier::<C>::try_from(n).unwrap();
+        let bytes = identifier.serialize();
+        assert_eq!(Identifier::try_from(bytes.as_ref()), Ok(identifier));
+    }
+
+    let derived = Identifier::<C>::derive(b"external id");
+    if let Ok(derived) = derived {
+        assert_eq!(
+            Identifier::try_from(derived.serialize().as_ref()),
+            Ok(derived)
+        );
+    }
+
+    let zero = <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero());
+    assert_eq!(
+        Identifier::<C>::try_from(zero.as_ref()),
+        Err(Error::FieldError(FieldError::InvalidZeroScalar))
+    );
+
+    let one = Identifier::<C>::try_from(1u16).unwrap().serialize();
+    let too_short = one.as_ref().split_last().unwrap().1;
+    assert_eq!(
+        Identifier::<C>::try_from(too_short),
+        Err(Error::MalformedIdentifier)
+    );
+    let too_long = [one.as_ref(), &[0]].concat();
+    assert_eq!(
+        Identifier::<C>::try_from(too_long.as_slice()),
+        Err(Error::MalformedIdentifier)
+    );
+}
+
+/// Test that public key packages record the number of signers.
+pub fn check_public_key_package_signer_counts<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(pubkeys.min_signers(), Some(min_signers));
+    assert_eq!(pubkeys.max_signers(), max_signers);
+
+    // Recreating the package from the commitment preserves the threshold.
+    let identifiers = shares.keys().copied().collect();
+    let commitment = shares.values().next().unwrap().commitment();
+    let recreated = PublicKeyPackage::from_commitment(&identifiers, commitment).unwrap();
+    assert_eq!(recreated, pubkeys);
+
+    // Packages created with `new` don't know the threshold.
+    let pubkeys =
+        PublicKeyPackage::new(pubkeys.verifying_shares().clone(), *pubkeys.verifying_key());
+    assert_eq!(pubkeys.min_signers(), None);
+    assert_eq!(pubkeys.max_signers(), max_signers);
+}
+
+/// Test checking that signing commitments match their nonces.
+pub fn check_verify_commitments<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_share =
+        frost::keys::SigningShare::new(<<C::Group as Group>::Field>::random(&mut rng));
+    let (nonces, commitments) = frost::round1::commit::<C, _>(&signing_share, &mut rng);
+    assert!(nonces.verify_commitments(&commitments));
+
+    let (other_nonces, other_commitments) = frost::round1::commit::<C, _>(&signing_share, &mut rng);
+    assert!(!nonces.verify_commitments(&other_commitments));
+    assert!(!other_nonces.verify_commitments(&commitments));
+
+    // Swapping the hiding and binding commitments must be detected.
+    let swapped =
+        frost::round1::SigningCommitments::new(*commitments.binding(), *commitments.hiding());
+    assert!(!nonces.verify_commitments(&swapped));
+    // As well as a single mismatched commitment.
+    let mixed =
+        frost::round1::SigningCommitments::new(*commitments.hiding(), *other_commitments.binding());
+    assert!(!nonces.verify_commitments(&mixed));
+}
+
+/// Test single-signer signing with a reconstructed key.
+pub fn check_sign_with_reconstructed_key<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: Vec<_> = frost::keys::key_packages(&shares)
+        .unwrap()
+        .into_values()
+        .take(min_signers as usize)
+        .collect();
+
+    let signing_key = frost::keys::reconstruct(&key_packages).unwrap();
+    assert_eq!(signing_key.verifying_key(), *pubkeys.verifying_key());
+
+    let message = b"emergency single-signer message";
+    let signature = signing_key.sign(&mut rng, message);
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+    assert!(pubkeys
+        .verifying_key()
+        .verify(b"another message", &signature)
+        .is_err());
+}
+
+/// Test enumerating the minimal signing quorums of a public key package.
+pub fn check_minimal_quorums<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 4;
+    let min_signers = 2;
+    let (_shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let quorums: Vec<_> = pubkeys.minimal_quorums().unwrap().collect();
+    assert_eq!(quorums.len(), 6);
+    assert!(quorums.iter().all(|q| q.len() == min_signers as usize
+        && q.iter()
+            .all(|id| pubkeys.verifying_shares().contains_key(id))));
+    assert_eq!(
+        quorums
+            .iter()
+            .collect::<std::collections::BTreeSet<_>>()
+            .len(),
+        quorums.len()
+    );
+
+    // Packages which don't know the threshold can't enumerate quorums.
+    let pubkeys =
+        PublicKeyPackage::new(pubkeys.verifying_shares().clone(), *pubkeys.verifying_key());
+    assert!(matches!(
+        pubkeys.minimal_quorums(),
+        Err(Error::InvalidMinSigners)
+    ));
+}
+
+/// Test finding the expected signers missing from a signing package.
+pub fn check_missing_signers<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let expected: std::collections::BTreeSet<_> = shares.keys().copied().collect();
+
+    // Only the first two signers have sent their commitments.
+    let commitments: BTreeMap<_, _> = shares
+        .iter()
+        .take(2)
+        .map(|(id, share)| {
+            (
+                *id,
+                frost::round1::commit(share.signing_share(), &mut rng).1,
+            )
+        })
+        .collect();
+    let signing_package = frost::SigningPackage::new(commitments.clone(), b"message to sign");
+
+    let missing = signing_package.missing_signers(&expected);
+    assert_eq!(missing, expected.iter().skip(2).copied().collect());
+    assert!(missing.iter().all(|id| !commitments.contains_key(id)));
+
+    // Unexpected signers in the package are not reported.
+    let expected_subset = expected.iter().take(3).copied().collect();
+    assert_eq!(
+        signing_package.missing_signers(&expected_subset),
+        expected.iter().skip(2).take(1).copied().collect()
+    );
+    assert!(signing_package
+        .missing_signers(&commitments.keys().copied().collect())
+        .is_empty());
+}
+
+/// Test that identifiers are ordered by the integer value of their scalar.
+pub fn check_identifier_ordering<C: Ciphersuite>() {
+    let identifiers: Vec<Identifier<C>> = (1u16..=10)
+        .chain([255, 256, 257, 1000, u16::MAX])
+        .map(|i| i.try_into().unwrap())
+        .collect();
+    let mut sorted = identifiers.clone();
+    sorted.reverse();
+    sorted.sort();
+    assert_eq!(sorted, identifiers);
+
+    // The order matches comparing the little-endian scalar encodings from the
+    // most significant byte.
+    let one = <<C::Group as Group>::Field>::one();
+    for a in &identifiers {
+        for b in &identifiers {
+            let a_bytes = <<C::Group as Group>::Field>::little_endian_serialize(&(*a * one));
+            let b_bytes = <<C::Group as Group>::Field>::little_endian_serialize(&(*b * one));
+            assert_eq!(
+                a.cmp(b),
+                a_bytes
+                    .as_ref()
+                    .iter()
+                    .rev()
+                    .cmp(b_bytes.as_ref().iter().rev())
+            );
+        }
+    }
+}
+
+/// Test that the DKG completes when every participant persists and restores
+/// their secret packages between rounds.
+#[cfg(feature = "serialization")]
+pub fn check_dkg_with_serialized_secret_packages<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let identifiers: Vec<Identifier<C>> =
+        (1..=max_signers).map(|i| i.try_into().unwrap()).collect();
+
+    // Round 1; only the serialized secret packages are kept.
+    let mut round1_secret_packages = BTreeMap::new();
+    let mut round1_packages = BTreeMap::new();
+    for id in &identifiers {
+        let (secret_package, package) =
+            frost::keys::dkg::part1(*id, max_signers, min_signers, &mut rng).unwrap();
+        round1_secret_packages.insert(*id, secret_package.serialize().unwrap());
+        round1_packages.insert(*id, package);
+    }
+
+    // Round 2, restoring the round 1 state.
+    let mut round2_secret_packages = BTreeMap::new();
+    let mut round2_packages: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+    for id in &identifiers {
+        let secret_package =
+            frost::keys::dkg::round1::SecretPackage::<C>::deserialize(&round1_secret_packages[id])
+                .unwrap();
+        assert_eq!(
+            secret_package.serialize().unwrap(),
+            round1_secret_packages[id]
+        );
+        let received = round1_packages
+            .iter()
+            .filter(|(sender, _)| *sender != id)
+            .map(|(sender, package)| (*sender, package.clone()))
+            .collect();
+        let (secret_package, packages) =
+            frost::keys::dkg::part2(secret_package, &received).unwrap();
+        round2_secret_packages.insert(*id, secret_package.serialize().unwrap());
+        for (receiver, package) in packages {
+            round2_packages
+                .entry(receiver)
+                .or_default()
+                .insert(*id, package);
+        }
+    }
+
+    // Part 3, restoring the round 2 state.
+    let mut verifying_keys = Vec::new();
+    for id in &identifiers {
+        let secret_package =
+            frost::keys::dkg::round2::SecretPackage::<C>::deserialize(&round2_secret_packages[id])
+                .unwrap();
+        let received_round1 = round1_packages
+            .iter()
+            .filter(|(sender, _)| *sender != id)
+            .map(|(sender, package)| (*sender, package.clone()))
+            .collect();
+        let (key_package, pubkeys) =
+            frost::keys::dkg::part3(&secret_package, &received_round1, &round2_packages[id])
+                .unwrap();
+        assert_eq!(key_package.verifying_key(), pubkeys.verifying_key());
+        verifying_keys.push(*pubkeys.verifying_key());
+    }
+    assert!(verifying_keys.windows(2).all(|w| w.first() == w.last()));
+}
+
+/// Test that signing is refused with a key package without the sign capability.
+pub fn check_sign_with_capabilities<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, _pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(2) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    let (id, key_package) = key_packages.iter().next().unwrap();
+    assert_eq!(*key_package.capabilities(), frost::keys::Capabilities::ALL);
+    let nonces = &nonces_map[id];
+
+    let repair_only = key_package
+        .clone()
+        .with_capabilities(frost::keys::Capabilities::REPAIR);
+    assert_eq!(
+        frost::round2::sign(&signing_package, nonces, &repair_only),
+        Err(Error::MissingCapability)
+    );
+
+    let stripped = key_package
+        .clone()
+        .with_capabilities(frost::keys::Capabilities::ALL.without(frost::keys::Capabilities::SIGN));
+    assert_eq!(
+        frost::round2::sign(&signing_package, nonces, &stripped),
+        Err(Error::MissingCapability)
+    );
+
+    // The other signing paths enforce it too.
+    let backend = frost::round2::SoftwareBackend::new(*key_package.signing_share());
+    assert_eq!(
+        frost::round2::sign_with_backend(
+            &signing_package,
+            nonces,
+            &stripped.public_part(),
+            &backend
+        ),
+        Err(Error::MissingCapability)
+    );
+    assert_eq!(
+        frost::round2::participation_receipt(&stripped, b"session", b"message", &mut rng),
+        Err(Error::MissingCapability)
+    );
+
+    // The capabilities survive serialization.
+    #[cfg(feature = "serialization")]
+    {
+        let bytes = stripped.serialize().unwrap();
+        assert_eq!(
+            frost::keys::KeyPackage::<C>::deserialize(&bytes).unwrap(),
+            stripped
+        );
+        let bytes = stripped.public_part().serialize().unwrap();
+        assert_eq!(
+            frost::keys::PublicKeyComponent::<C>::deserialize(&bytes).unwrap(),
+            stripped.public_part()
+        );
+    }
+
+    assert!(frost::round2::sign(&signing_package, nonces, key_package).is_ok());
+    assert!(frost::round2::sign_with_backend(
+        &signing_package,
+        nonces,
+        &key_package.public_part(),
+        &backend
+    )
+    .is_ok());
+}
+
+/// Test one-round signing with preprocessed commitments and
+/// [`frost::round2::sign_preprocessed`].
+pub fn check_sign_preprocessed<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    // Ahead of time, each participant preprocesses nonces, keeps them in a
+    // nonce pool and publishes the commitments to the coordinator.
+    let mut commitment_pool = frost::round1::CommitmentPool::new();
+    let mut nonce_pools = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) =
+            frost::round1::preprocess(2, key_package.signing_share(), &mut rng);
+        commitment_pool.add(*id, commitments);
+        nonce_pools.insert(*id, frost::round1::NoncePool::new(nonces));
+    }
+
+    let signers: std::collections::BTreeSet<_> = key_packages
+        .keys()
+        .take(min_signers as usize)
+        .copied()
+        .collect();
+
+    // Each signing operation then takes a single round.
+    for message in [b"first message".as_slice(), b"second message".as_slice()] {
+        let signing_package = commitment_pool.signing_package(&signers, message).unwrap();
+
+        let mut signature_shares = BTreeMap::new();
+        for id in &signers {
+            let nonce_pool = nonce_pools.get_mut(id).unwrap();
+            let signature_share =
+                frost::round2::sign_preprocessed(&signing_package, nonce_pool, &key_packages[id])
+                    .unwrap();
+            signature_shares.insert(*id, signature_share);
+
+            // The nonces can't be used twice.
+            assert_eq!(
+                frost::round2::sign_preprocessed(&signing_package, nonce_pool, &key_packages[id]),
+                Err(Error::IncorrectCommitment)
+            );
+        }
+
+        let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+        pubkeys.verifying_key().verify(message, &signature).unwrap();
+    }
+    assert!(signers.iter().all(|id| nonce_pools[id].is_empty()));
+
+    // All the signers' commitments were used.
+    assert_eq!(
+        commitment_pool.signing_package(&signers, b"message"),
+        Err(Error::MissingCommitment)
+    );
+
+    // A participant without a commitment in the signing package can't sign.
+    let (id, key_package) = key_packages.iter().last().unwrap();
+    let commitment = commitment_pool.take_one(id).unwrap();
+    let signing_package = frost::SigningPackage::new(
+        BTreeMap::from([(*signers.first().unwrap(), commitment)]),
+        b"message",
+    );
+    assert_eq!(
+        frost::round2::sign_preprocessed(
+            &signing_package,
+            nonce_pools.get_mut(id).unwrap(),
+            key_package
+        ),
+        Err(Error::MissingCommitment)
+    );
+    assert_eq!(nonce_pools[id].len(), 2);
+}
+
+/// Test that [`frost::keys::SigningShare::entropy_ok`] flags tiny shares.
+pub fn check_signing_share_entropy<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let one = <<C::Group as Group>::Field>::one();
+    let tiny = one + one + one;
+    let tiny_share =
+        frost::keys::SigningShare::<C>::deserialize(<<C::Group as Group>::Field>::serialize(&tiny))
+            .unwrap();
+    assert!(tiny_share.entropy_ok(2));
+    assert!(!tiny_share.entropy_ok(3));
+    assert!(!tiny_share.entropy_ok(64));
+
+    // The negation of a tiny scalar is flagged too.
+    let negated = <<C::Group as Group>::Field>::zero() - tiny;
+    let negated_share = frost::keys::SigningShare::<C>::deserialize(
+        <<C::Group as Group>::Field>::serialize(&negated),
+    )
+    .unwrap();
+    assert!(!negated_share.entropy_ok(64));
+
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    for share in shares.values() {
+        assert!(share.signing_share().entropy_ok(64));
+    }
+}
+
+/// Test that [`frost::aggregate_with_details`] returns the same signature as
+/// [`frost::aggregate`] and intermediate values matching independently
+/// recomputed ones.
+pub fn check_aggregate_with_details<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(3) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let message = b"message to sign";
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    let signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+            (*id, share)
+        })
+        .collect();
+
+    let (signature, details) =
+        frost::aggregate_with_details(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert_eq!(
+        signature,
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap()
+    );
+
+    let group_commitment =
+        frost::aggregate_commitment(&signing_package, pubkeys.verifying_key()).unwrap();
+    assert!(*details.group_commitment() == group_commitment);
+    assert!(signature.R == group_commitment);
+
+    let binding_factors: BTreeMap<_, _> = signing_package
+        .binding_factor_preimages(pubkeys.verifying_key(), &[])
+        .iter()
+        .map(|(id, preimage)| (*id, frost::BindingFactor::new(C::H1(preimage))))
+        .collect();
+    assert_eq!(*details.binding_factors(), binding_factors);
+
+    let mut preimage = vec![];
+    preimage.extend_from_slice(<C::Group>::serialize(&group_commitment).as_ref());
+    preimage
+        .extend_from_slice(<C::Group>::serialize(&pubkeys.verifying_key().to_element()).as_ref());
+    preimage.extend_from_slice(message);
+    assert!(*details.challenge() == C::H2(&preimage));
+}
+
+/// Test threading a single RNG through a whole ceremony: every function
+/// consuming randomness takes it by mutable reference.
+pub fn check_single_rng_ceremony<C: Ciphersuite>() {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let rng = &mut rng;
+
+    // DKG.
+    let identifiers: Vec<frost::Identifier<C>> =
+        (1..=3u16).map(|i| i.try_into().unwrap()).collect();
+    for id in &identifiers {
+        frost::keys::dkg::part1(*id, 3, 2, rng).unwrap();
+    }
+
+    // Trusted dealer.
+    let signing_key = frost::SigningKey::<C>::new(rng);
+    let (shares, pubkeys) = frost::keys::split(
+        &signing_key,
+        3,
+        2,
+        frost::keys::IdentifierList::Default,
+        rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    // Signing.
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let message = b"message to sign";
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    let signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+            (*id, share)
+        })
+        .collect();
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    // Proofs of possession and single-key signatures.
+    for (id, key_package) in &key_packages {
+        let proof = frost::keys::proof_of_possession(key_package, rng);
+        frost::keys::verify_proof_of_possession(key_package.verifying_share(), *id, &proof)
+            .unwrap();
+    }
+    let single_signature = signing_key.sign(rng, message);
+
+    // Batch verification.
+    let mut batch = frost::batch::Verifier::<C>::new();
+    batch.queue((*pubkeys.verifying_key(), signature, message));
+    batch.queue((signing_key.verifying_key(), single_signature, message));
+    batch.verify(rng).unwrap();
+}
+
+/// Test that [`frost::keys::test_dealer`] is deterministic and uses the test
+/// identifiers.
+#[cfg(feature = "test-vectors")]
+pub fn check_test_dealer<C: Ciphersuite>() {
+    let (shares, pubkeys) = frost::keys::test_dealer::<C>(5, 3).unwrap();
+    assert_eq!(
+        (shares.clone(), pubkeys.clone()),
+        frost::keys::test_dealer::<C>(5, 3).unwrap()
+    );
+
+    let identifiers: Vec<_> = (1..=5).map(Identifier::<C>::test_identifier).collect();
+    assert_eq!(shares.keys().copied().collect::<Vec<_>>(), identifiers);
+    assert_eq!(
+        Identifier::<C>::test_identifier(1),
+        1u16.try_into().unwrap()
+    );
+    assert_eq!(
+        Identifier::<C>::test_identifier(u16::MAX),
+        u16::MAX.try_into().unwrap()
+    );
+
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+    let rng = <rand_chacha::ChaCha20Rng as rand_chacha::rand_core::SeedableRng>::seed_from_u64(0);
+    check_sign(3, key_packages, rng, pubkeys).unwrap();
+}
+
+/// Test that [`frost::aggregate`] rejects a signature share from a signer
+/// without a commitment in the signing package.
+pub fn check_aggregate_rejects_unexpected_signer<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(3) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+    let mut signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+            (*id, share)
+        })
+        .collect();
+
+    // An extra share from a signer that never committed.
+    let uncommitted = *key_packages.keys().last().unwrap();
+    let share = *signature_shares.values().next().unwrap();
+    let mut extra_shares = signature_shares.clone();
+    extra_shares.insert(uncommitted, share);
+    assert_eq!(
+        frost::aggregate(&signing_package, &extra_shares, &pubkeys),
+        Err(Error::UnexpectedSigner {
+            culprit: uncommitted
+        })
+    );
+
+    // A share from a signer that never committed replacing a committed one.
+    signature_shares.pop_first();
+    signature_shares.insert(uncommitted, share);
+    let error = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap_err();
+    assert_eq!(
+        error,
+        Error::UnexpectedSigner {
+            culprit: uncommitted
+        }
+    );
+    assert_eq!(error.culprit(), Some(uncommitted));
+}
+
+/// Test collecting signature shares with a [`frost::Aggregator`].
+pub fn check_aggregator<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(3) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let message = b"message to sign";
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut aggregator = frost::Aggregator::new(signing_package.clone(), pubkeys.clone());
+    assert_eq!(aggregator.collected(), 0);
+    assert_eq!(aggregator.remaining(), 3);
+    assert!(!aggregator.is_ready());
+    assert_eq!(aggregator.aggregate(), Err(Error::IncorrectNumberOfShares));
+
+    for (i, (id, nonces)) in nonces_map.iter().enumerate() {
+        let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+        aggregator.add(*id, share).unwrap();
+        assert_eq!(aggregator.collected(), i + 1);
+        assert_eq!(aggregator.remaining(), 2 - i);
+        assert_eq!(aggregator.is_ready(), i == 2);
+
+        assert_eq!(aggregator.add(*id, share), Err(Error::DuplicatedShares));
+        let uncommitted = *key_packages.keys().last().unwrap();
+        assert_eq!(
+            aggregator.add(uncommitted, share),
+            Err(Error::UnexpectedSigner {
+                culprit: uncommitted
+            })
+        );
+    }
+
+    let signature = aggregator.aggregate().unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
+
+/// Test one-shot signing with [`frost::simple::sign_all`].
+pub fn check_simple_sign_all<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .take(3)
+        .collect();
+
+    let message = b"message to sign";
+    let signature = frost::simple::sign_all(message, &key_packages, &pubkeys, &mut rng).unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
+
+/// Test [`frost::keys::KeyPackage::check_group`] with matching and mismatched
+/// public key packages.
+pub fn check_key_package_check_group<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let (_, other_pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+
+    for share in shares.into_values() {
+        let key_package: frost::keys::KeyPackage<C> = share.try_into().unwrap();
+        key_package.check_group(&pubkeys).unwrap();
+        assert_eq!(
+            key_package.check_group(&other_pubkeys),
+            Err(Error::GroupKeyMismatch)
+        );
+    }
+}
+
+/// Test that [`frost::simple::sign_all_with_nonces`] produces the same
+/// signature when given the same nonces.
+#[cfg(feature = "test-vectors")]
+pub fn check_sign_all_with_fixed_nonces<C: Ciphersuite>() {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    let (shares, pubkeys) = frost::keys::test_dealer::<C>(5, 3).unwrap();
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .take(3)
+        .collect();
+
+    let fixed_nonces = || {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        key_packages
+            .iter()
+            .map(|(id, key_package)| {
+                (
+                    *id,
+                    frost::round1::SigningNonces::new(key_package.signing_share(), &mut rng),
+                )
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    let message = b"message to sign";
+    let signature1 =
+        frost::simple::sign_all_with_nonces(message, &key_packages, &pubkeys, &fixed_nonces())
+            .unwrap();
+    let signature2 =
+        frost::simple::sign_all_with_nonces(message, &key_packages, &pubkeys, &fixed_nonces())
+            .unwrap();
+    pubkeys
+        .verifying_key()
+        .verify(message, &signature1)
+        .unwrap();
+    assert!(signature1.R == signature2.R);
+    assert!(signature1.z == signature2.z);
+
+    // Missing nonces for a signer are rejected.
+    let mut nonces = fixed_nonces();
+    nonces.pop_first();
+    assert_eq!(
+        frost::simple::sign_all_with_nonces(message, &key_packages, &pubkeys, &nonces),
+        Err(Error::MissingCommitment)
+    );
+}
+
+/// Test signing a stream with a [`frost::chained::ChainedSigningSession`] and
+/// verifying it with [`frost::chained::verify_chain`].
+pub fn check_chained_signing<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .take(3)
+        .collect();
+
+    let chunks: [&[u8]; 3] = [b"first chunk", b"second chunk", b"third chunk"];
+    let mut session = frost::chained::ChainedSigningSession::new();
+    let mut signatures = Vec::new();
+    for (i, chunk) in chunks.into_iter().enumerate() {
+        let mut nonces_map = BTreeMap::new();
+        let mut commitments_map = BTreeMap::new();
+        for (id, key_package) in &key_packages {
+            let (nonces, commitments) =
+                frost::round1::commit(key_package.signing_share(), &mut rng);
+            nonces_map.insert(*id, nonces);
+            commitments_map.insert(*id, commitments);
+        }
+        let signing_package =
+            session.signing_package(commitments_map, chunk, i + 1 == chunks.len());
+        let signature_shares: BTreeMap<_, _> = nonces_map
+            .iter()
+            .map(|(id, nonces)| {
+                let share =
+                    frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+                (*id, share)
+            })
+            .collect();
+        signatures.push(
+            session
+                .aggregate(&signing_package, &signature_shares, &pubkeys)
+                .unwrap(),
+        );
+    }
+
+    frost::chained::verify_chain(&chunks, &signatures, pubkeys.verifying_key()).unwrap();
+
+    // Reordered chunks (with their signatures) are detected.
+    let reordered_chunks = [chunks[0], chunks[2], chunks[1]];
+    let reordered_signatures = [signatures[0], signatures[2], signatures[1]];
+    assert_eq!(
+        frost::chained::verify_chain(
+            &reordered_chunks,
+            &reordered_signatures,
+            pubkeys.verifying_key()
+        ),
+        Err(Error::InvalidSignature)
+    );
+
+    // A reordered chunk is detected.
+    assert_eq!(
+        frost::chained::verify_chain(&reordered_chunks, &signatures, pubkeys.verifying_key()),
+        Err(Error::InvalidSignature)
+    );
+
+    // A dropped chunk is detected.
+    assert_eq!(
+        frost::chained::verify_chain(&chunks[1..], &signatures[1..], pubkeys.verifying_key()),
+        Err(Error::InvalidSignature)
+    );
+
+    // A truncated stream is detected.
+    assert_eq!(
+        frost::chained::verify_chain(&chunks[..2], &signatures[..2], pubkeys.verifying_key()),
+        Err(Error::InvalidSignature)
+    );
+}
+
+/// Test that [`Error::code`] returns the documented, distinct codes.
+pub fn check_error_codes<C: Ciphersuite>() {
+    let id = Identifier::<C>::try_from(1).unwrap();
+    let expected: Vec<(Error<C>, u32)> = vec![
+        (Error::InvalidMinSigners, 1),
+        (Error::InvalidMaxSigners, 2),
+        (Error::InvalidCoefficients, 3),
+        (Error::MalformedIdentifier, 4),
+        (Error::DuplicatedIdentifier, 5),
+        (Error::UnknownIdentifier, 6),
+        (Error::IncorrectNumberOfIdentifiers, 7),
+        (Error::MalformedSigningKey, 8),
+        (Error::MalformedVerifyingKey, 9),
+        (Error::MalformedSignature, 10),
+        (Error::InvalidSignature, 11),
+        (Error::IdentitySignatureCommitment, 12),
+        (Error::ZeroSignatureResponse, 13),
+        (Error::DuplicatedShares, 14),
+        (Error::IncorrectNumberOfShares, 15),
+        (Error::IdentityCommitment, 16),
+        (Error::MissingCommitment, 17),
+        (Error::IncorrectCommitment, 18),
+        (Error::IncorrectNumberOfCommitments, 19),
+        (Error::InvalidSignatureShare { culprit: id }, 20),
+        (Error::InvalidSecretShare, 21),
+        (Error::PackageNotFound, 22),
+        (Error::IncorrectNumberOfPackages, 23),
+        (Error::IncorrectPackage, 24),
+        (Error::DKGNotSupported, 25),
+        (Error::InvalidProofOfKnowledge { culprit: id }, 26),
+        (Error::InvalidProofOfPossession { culprit: id }, 27),
+        (Error::UnexpectedSigner { culprit: id }, 28),
+        (Error::GroupKeyMismatch, 29),
+        (Error::MissingCapability, 30),
+        (Error::InvalidCoefficient, 31),
+        (Error::IdentifierDerivationNotSupported, 32),
+        (Error::SerializationError, 33),
+        (Error::DeserializationError, 34),
+        (Error::CiphersuiteMismatch, 35),
+        (Error::UnsupportedVersion, 36),
+        (Error::MessageDigestMismatch, 37),
+        (Error::TooManySigners, 38),
+        (Error::SignerBackendFailure, 39),
+        (Error::InconsistentPublicKeyPackage, 40),
+        (Error::MessageTooLong, 41),
+        (Error::DegenerateCommitment { culprit: id }, 42),
+        (Error::NoncesAborted, 43),
+        (Error::InvalidCommitmentSignature { culprit: id }, 44),
+        (
+            Error::CollidingCommitments {
+                first: id,
+                second: id,
+            },
+            45,
+        ),
+        (Error::InvalidParticipantSecretShare { culprit: id }, 46),
+        (Error::SignerBackendMismatch, 47),
+        (Error::InsufficientShares, 48),
+        (Error::FieldError(FieldError::MalformedScalar), 1001),
+        (Error::FieldError(FieldError::InvalidZeroScalar), 1002),
+        (Error::GroupError(GroupError::MalformedElement), 1101),
+        (Error::GroupError(GroupError::InvalidIdentityElement), 1102),
+        (
+            Error::GroupError(GroupError::InvalidNonPrimeOrderElement),
+            1103,
+        ),
+    ];
+    for (error, code) in &expected {
+        assert_eq!(error.code(), *code, "{error:?}");
+    }
+    let codes: std::collections::BTreeSet<_> = expected.iter().map(|(_, code)| code).collect();
+    assert_eq!(codes.len(), expected.len());
+    assert_eq!(frost::ERROR_CODE_VERSION, 1);
+}
+
+/// Test that [`frost::keys::split_with_polynomial`] evaluates the given
+/// polynomial.
+pub fn check_split_with_polynomial<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let coefficients: Vec<_> = (0..min_signers)
+        .map(|_| <<C::Group as Group>::Field>::random(&mut rng))
+        .collect();
+
+    let (shares, pubkeys) = frost::keys::split_with_polynomial(
+        &coefficients,
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+    )
+    .unwrap();
+
+    assert!(pubkeys.verifying_key().to_element() == <C::Group>::generator() * coefficients[0]);
+    for (identifier, share) in &shares {
+        // f(x) = a_0 + a_1 * x + a_2 * x^2
+        let x = *identifier * <<C::Group as Group>::Field>::one();
+        let expected = coefficients[0] + coefficients[1] * x + coefficients[2] * x * x;
+        assert!(share.signing_share().to_scalar() == expected);
+        assert_eq!(
+            *share.commitment(),
+            frost::keys::VerifiableSecretSharingCommitment::<C>::new(
+                coefficients
+                    .iter()
+                    .map(|c| frost::keys::CoefficientCommitment::new(<C::Group>::generator() * *c))
+                    .collect()
+            )
+        );
+        share.verify().unwrap();
+    }
+
+    // The shares work for signing.
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+    check_sign(min_signers, key_packages, rng, pubkeys).unwrap();
+
+    // The number of coefficients must be min_signers.
+    let too_many = [coefficients.clone(), coefficients.clone()].concat();
+    for coefficients in [&coefficients[..2], &[], &too_many] {
+        assert_eq!(
+            frost::keys::split_with_polynomial::<C>(
+                coefficients,
+                max_signers,
+                min_signers,
+                frost::keys::IdentifierList::Default,
+            ),
+            Err(Error::InvalidCoefficients)
+        );
+    }
+}
+
+/// Test that a signature made with [`SigningPackage::new_bound_to_set`] only
+/// verifies for the same set of signers.
+///
+/// [`SigningPackage::new_bound_to_set`]: frost::SigningPackage::new_bound_to_set
+pub fn check_sign_bound_to_set<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        5,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(3)
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+    let message = b"message to sign";
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (n, c) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces.insert(*identifier, n);
+        commitments.insert(*identifier, c);
+    }
+    let signing_package = frost::SigningPackage::new_bound_to_set(commitments, message);
+    let signature_shares = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            let share = frost::round2::sign(&signing_package, &nonces[identifier], key_package);
+            (*identifier, share.unwrap())
+        })
+        .collect();
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    let verifying_key = pubkeys.verifying_key();
+    let signers: BTreeSet<_> = key_packages.keys().copied().collect();
+    verifying_key
+        .verify_bound_to_set(&signers, message, &signature)
+        .unwrap();
+
+    // A different claimed signing set, or no set at all, must be rejected.
+    let mut other_signers = signers.clone();
+    other_signers.pop_first();
+    other_signers.insert(*pubkeys.verifying_shares().keys().last().unwrap());
+    assert_eq!(
+        verifying_key.verify_bound_to_set(&other_signers, message, &signature),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verifying_key.verify(message, &signature),
+        Err(Error::InvalidSignature)
+    );
+}
+
+/// Test that [`Ciphersuite::signature_len`] matches the length of serialized
+/// signatures.
+pub fn check_signature_len<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let signature = signing_key.sign(&mut rng, b"message");
+
+    assert_eq!(signature.serialize().as_ref().len(), C::signature_len());
+}
+
+/// Test that messages exceeding the message limit are rejected.
+pub fn check_message_limit<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, _pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(2) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+
+    let message = b"message to sign";
+    assert_eq!(
+        frost::SigningPackage::new_with_message_limit(commitments_map.clone(), message, 14)
+            .unwrap_err(),
+        Error::MessageTooLong
+    );
+    assert!(
+        frost::SigningPackage::new_with_message_limit(commitments_map.clone(), message, 15).is_ok()
+    );
+
+    // Signers can reject oversized messages even if the package was created
+    // without a limit.
+    let signing_package = frost::SigningPackage::new(commitments_map.clone(), message);
+    for (id, nonces) in &nonces_map {
+        assert_eq!(
+            frost::round2::sign_with_message_limit(&signing_package, nonces, &key_packages[id], 14),
+            Err(Error::MessageTooLong)
+        );
+        assert_eq!(
+            frost::round2::sign_with_message_limit(&signing_package, nonces, &key_packages[id], 15),
+            frost::round2::sign(&signing_package, nonces, &key_packages[id])
+        );
+    }
+
+    // By default, they reject messages longer than `DEFAULT_MESSAGE_LIMIT`.
+    let long_message = vec![0; frost::DEFAULT_MESSAGE_LIMIT + 1];
+    let signing_package = frost::SigningPackage::new(commitments_map, &long_message);
+    for (id, nonces) in &nonces_map {
+        assert_eq!(
+            frost::round2::sign(&signing_package, nonces, &key_packages[id]),
+            Err(Error::MessageTooLong)
+        );
+        assert!(frost::round2::sign_with_message_limit(
+            &signing_package,
+            nonces,
+            &key_packages[id],
+            long_message.len()
+        )
+        .is_ok());
+    }
+
+    // Deserialization rejects messages longer than `DEFAULT_MESSAGE_LIMIT`.
+    #[cfg(feature = "serialization")]
+    {
+        let bytes = signing_package.serialize().unwrap();
+        assert_eq!(
+            frost::SigningPackage::<C>::deserialize(&bytes).unwrap_err(),
+            Error::DeserializationError
+        );
+
+        let signing_package = frost::SigningPackage::new(
+            signing_package.signing_commitments().clone(),
+            &long_message[1..],
+        );
+        let bytes = signing_package.serialize().unwrap();
+        assert_eq!(
+            frost::SigningPackage::<C>::deserialize(&bytes).unwrap(),
+            signing_package
+        );
+    }
+}
+
+/// Test that [`frost::round1::commit_from_seed`] is deterministic, so that
+/// signing sessions can be reproduced.
+pub fn check_commit_from_seed<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+    let mut seeds = BTreeMap::new();
+    for id in key_packages.keys() {
+        let mut seed = [0; 32];
+        rng.fill_bytes(&mut seed);
+        seeds.insert(*id, seed);
+    }
+
+    let sign = |counter: u64| {
+        let mut nonces_map = BTreeMap::new();
+        let mut commitments_map = BTreeMap::new();
+        for (id, key_package) in &key_packages {
+            let (nonces, commitments) =
+                frost::round1::commit_from_seed(key_package.signing_share(), seeds[id], counter);
+            nonces_map.insert(*id, nonces);
+            commitments_map.insert(*id, commitments);
+        }
+        let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+        let signature_shares = nonces_map
+            .iter()
+            .map(|(id, nonces)| {
+                let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]);
+                (*id, share.unwrap())
+            })
+            .collect();
+        let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+        (nonces_map, signature)
+    };
+
+    let (nonces, signature) = sign(0);
+    let (same_nonces, same_signature) = sign(0);
+    assert_eq!(nonces, same_nonces);
+    assert!(signature == same_signature);
+
+    let (other_nonces, other_signature) = sign(1);
+    assert_ne!(nonces, other_nonces);
+    assert!(signature != other_signature);
+
+    // The hiding and binding nonces are distinct.
+    for nonces in nonces.values() {
+        assert!(nonces.hiding() != nonces.binding());
+    }
+}
+
+/// Test that [`frost::round2::batch_verify_shares`] accepts valid shares and
+/// identifies an invalid one.
+pub fn check_batch_verify_shares<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+    let mut signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]);
+            (*id, share.unwrap())
+        })
+        .collect();
+
+    frost::round2::batch_verify_shares(&signing_package, &signature_shares, &pubkeys, &mut rng)
+        .unwrap();
+
+    // Corrupt one share.
+    let culprit = *signature_shares.keys().nth(2).unwrap();
+    let one = <<C::Group as Group>::Field>::one();
+    let share = signature_shares.get_mut(&culprit).unwrap();
+    share.share = share.share + one;
+    assert_eq!(
+        frost::round2::batch_verify_shares(&signing_package, &signature_shares, &pubkeys, &mut rng),
+        Err(Error::InvalidSignatureShare { culprit })
+    );
+
+    // Shares from signers without a commitment are rejected.
+    let mut signature_shares = signature_shares.clone();
+    let unknown = Identifier::try_from(100).unwrap();
+    signature_shares.insert(unknown, signature_shares[&culprit]);
+    assert_eq!(
+        frost::round2::batch_verify_shares(&signing_package, &signature_shares, &pubkeys, &mut rng),
+        Err(Error::UnknownIdentifier)
+    );
+}
+
+/// Test that framed signatures round-trip, and that malformed frames are
+/// rejected.
+pub fn check_signature_framing<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let signature = signing_key.sign(&mut rng, b"message");
+
+    let framed = signature.to_framed();
+    assert_eq!(framed[0], frost::SIGNATURE_FRAME_TAG);
+    assert_eq!(framed.len(), C::signature_len() + 2);
+    assert!(Signature::<C>::from_framed(&framed).unwrap() == signature);
+
+    // Truncated and over-long frames.
+    assert_eq!(
+        Signature::<C>::from_framed(&framed[..framed.len() - 1]),
+        Err(Error::MalformedSignature)
+    );
+    let mut over_long = framed.clone();
+    over_long.push(0);
+    assert_eq!(
+        Signature::<C>::from_framed(&over_long),
+        Err(Error::MalformedSignature)
+    );
+    assert_eq!(
+        Signature::<C>::from_framed(&[]),
+        Err(Error::MalformedSignature)
+    );
+    assert_eq!(
+        Signature::<C>::from_framed(&framed[..1]),
+        Err(Error::MalformedSignature)
+    );
+
+    // A length that doesn't match the ciphersuite's signature length.
+    let mut wrong_len = framed.clone();
+    wrong_len[1] += 1;
+    wrong_len.push(0);
+    assert_eq!(
+        Signature::<C>::from_framed(&wrong_len),
+        Err(Error::MalformedSignature)
+    );
+
+    // A wrong tag.
+    let mut wrong_tag = framed;
+    wrong_tag[0] ^= 1;
+    assert_eq!(
+        Signature::<C>::from_framed(&wrong_tag),
+        Err(Error::MalformedSignature)
+    );
+}
+
+/// Test that signing commitments with equal hiding and binding commitments
+/// are rejected.
+pub fn check_degenerate_commitment<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+
+    // Replace a signer's commitments with degenerate ones.
+    let culprit = *commitments_map.keys().last().unwrap();
+    let commitments = commitments_map[&culprit];
+    commitments_map.insert(
+        culprit,
+        frost::round1::SigningCommitments::new(*commitments.hiding(), *commitments.hiding()),
+    );
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+    assert_eq!(
+        signing_package.validate(),
+        Err(Error::DegenerateCommitment { culprit })
+    );
+
+    let (id, nonces) = nonces_map.first_key_value().unwrap();
+    assert_eq!(
+        frost::round2::sign(&signing_package, nonces, &key_packages[id]),
+        Err(Error::DegenerateCommitment { culprit })
+    );
+
+    // The package is rejected before the signer's nonces are consumed.
+    let mut nonce_pool = frost::round1::NoncePool::new(vec![nonces.clone()]);
+    assert_eq!(
+        frost::round2::sign_preprocessed(&signing_package, &mut nonce_pool, &key_packages[id]),
+        Err(Error::DegenerateCommitment { culprit })
+    );
+    assert_eq!(nonce_pool.len(), 1);
+
+    // Placeholder shares, since the culprit can't sign.
+    let one = <<C::Group as Group>::Field>::one();
+    let share = frost::round2::SignatureShare::<C>::deserialize(
+        <<C::Group as Group>::Field>::serialize(&one),
+    )
+    .unwrap();
+    let signature_shares = key_packages.keys().map(|id| (*id, share)).collect();
+    assert_eq!(
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys),
+        Err(Error::DegenerateCommitment { culprit })
+    );
+}
+
+/// Test recomputing the group verifying key from the public parts of a
+/// quorum's key packages.
+pub fn check_group_key_from_packages<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let components: Vec<_> = frost::keys::key_packages::<C>(&shares)
+        .unwrap()
+        .values()
+        .map(|key_package| key_package.public_part())
+        .collect();
+
+    for quorum in [&components[..3], &components[1..4], &components[..]] {
+        assert_eq!(
+            frost::keys::group_key_from_packages(quorum, 3).unwrap(),
+            *pubkeys.verifying_key()
+        );
+    }
+
+    assert_eq!(
+        frost::keys::group_key_from_packages(&components[..2], 3),
+        Err(Error::IncorrectNumberOfShares)
+    );
+    let duplicated = [
+        components[0].clone(),
+        components[0].clone(),
+        components[1].clone(),
+    ];
+    assert_eq!(
+        frost::keys::group_key_from_packages(&duplicated, 3),
+        Err(Error::DuplicatedIdentifier)
+    );
+
+    // A component with a wrong verifying share leads to a different key.
+    let mut tampered = components[..3].to_vec();
+    tampered[0] = frost::keys::PublicKeyComponent::new(
+        *tampered[0].identifier(),
+        *tampered[1].verifying_share(),
+        *tampered[0].verifying_key(),
+        3,
+    );
+    assert_eq!(
+        frost::keys::group_key_from_packages(&tampered, 3),
+        Err(Error::GroupKeyMismatch)
+    );
+}
+
+/// Test signing with an externally-supplied challenge.
+pub fn check_sign_with_challenge<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    let sign_with_challenge = |challenge| -> BTreeMap<_, _> {
+        nonces_map
+            .iter()
+            .map(|(id, nonces)| {
+                let share = frost::round2::sign_with_challenge(
+                    &signing_package,
+                    nonces,
+                    &key_packages[id],
+                    challenge,
+                );
+                (*id, share.unwrap())
+            })
+            .collect()
+    };
+
+    let signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]);
+            (*id, share.unwrap())
+        })
+        .collect();
+    let (signature, details) =
+        frost::aggregate_with_details(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    // Supplying the normally-derived challenge reproduces the standard signature.
+    let challenge = *details.challenge();
+    assert_eq!(sign_with_challenge(challenge), signature_shares);
+    let same_signature =
+        frost::aggregate_with_challenge(&signing_package, &signature_shares, &pubkeys, challenge)
+            .unwrap();
+    assert!(same_signature == signature);
+
+    // A different challenge produces a signature that only checks against it.
+    let other_challenge = <<C::Group as Group>::Field>::random(&mut rng);
+    let other_shares = sign_with_challenge(other_challenge);
+    let other_signature =
+        frost::aggregate_with_challenge(&signing_package, &other_shares, &pubkeys, other_challenge)
+            .unwrap();
+    assert!(pubkeys
+        .verifying_key()
+        .verify(b"message to sign", &other_signature)
+        .is_err());
+    assert_eq!(
+        frost::aggregate_with_challenge(&signing_package, &other_shares, &pubkeys, challenge),
+        Err(Error::InvalidSignatureShare {
+            culprit: *other_shares.keys().next().unwrap()
+        })
+    );
+}
+
+/// Test that [`VerifyingKey::verification_residual`] is the identity for
+/// valid signatures.
+pub fn check_verification_residual<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let verifying_key = signing_key.verifying_key();
+    let message = b"message";
+    let signature = signing_key.sign(&mut rng, message);
+
+    assert!(verifying_key.verification_residual(message, &signature) == C::Group::identity());
+
+    // Increasing z by one shifts the residual by the generator.
+    let one = <<C::Group as Group>::Field>::one();
+    let tampered = Signature {
+        R: signature.R,
+        z: signature.z + one,
+    };
+    assert!(verifying_key.verification_residual(message, &tampered) == C::Group::generator());
+    assert!(
+        verifying_key.verification_residual(b"other message", &signature) != C::Group::identity()
+    );
+}
+
+/// Test that participation receipts verify with the participant's verifying
+/// share only.
+pub fn check_participation_receipt<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+    let mut key_packages = key_packages.values();
+    let key_package = key_packages.next().unwrap();
+    let other_key_package = key_packages.next().unwrap();
+    let session_id = b"session 1";
+    let message = b"message to sign";
+
+    let receipt =
+        frost::round2::participation_receipt(key_package, session_id, message, &mut rng).unwrap();
+    let verifying_share = key_package.verifying_share();
+    assert_eq!(
+        *verifying_share,
+        pubkeys.verifying_shares()[key_package.identifier()]
+    );
+    frost::round2::verify_participation_receipt(verifying_share, session_id, message, &receipt)
+        .unwrap();
+
+    // The receipt doesn't verify for another session, message or participant.
+    for (verifying_share, session_id, message) in [
+        (verifying_share, &b"session 2"[..], &message[..]),
+        (verifying_share, session_id, b"other message"),
+        (other_key_package.verifying_share(), session_id, message),
+    ] {
+        assert_eq!(
+            frost::round2::verify_participation_receipt(
+                verifying_share,
+                session_id,
+                message,
+                &receipt
+            ),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    // A receipt forged with another participant's share doesn't verify.
+    let forged =
+        frost::round2::participation_receipt(other_key_package, session_id, message, &mut rng)
+            .unwrap();
+    assert_eq!(
+        frost::round2::verify_participation_receipt(verifying_share, session_id, message, &forged),
+        Err(Error::InvalidSignature)
+    );
+
+    // A signature made with the share over the message without its domain tag
+    // doesn't verify as a receipt.
+    let mut unprefixed = session_id.to_vec();
+    unprefixed.extend_from_slice(C::H4(message).as_ref());
+    let signature = frost::SigningKey::from_scalar(key_package.signing_share().to_scalar())
+        .sign(&mut rng, &unprefixed);
+    assert_eq!(
+        frost::round2::verify_participation_receipt(
+            verifying_share,
+            session_id,
+            message,
+            &signature
+        ),
+        Err(Error::InvalidSignature)
+    );
+}
+
+/// Test that aborted signing nonces are removed from the nonce pool, and that
+/// zeroized nonces are rejected when signing.
+pub fn check_abort_nonces<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, _pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in key_packages.iter().take(2) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    let (identifier, nonces) = nonces_map.pop_first().unwrap();
+    let key_package = &key_packages[&identifier];
+
+    // A zeroized copy of the nonces can't be used for signing.
+    let mut zeroized = nonces.clone();
+    zeroized.zeroize();
+    let zero = <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero());
+    assert!(zeroized.hiding().serialize().as_ref() == zero.as_ref());
+    assert!(zeroized.binding().serialize().as_ref() == zero.as_ref());
+    assert_eq!(
+        frost::round2::sign(&signing_package, &zeroized, key_package),
+        Err(Error::NoncesAborted)
+    );
+
+    // Aborting the nonces also consumes their copy in the pool.
+    let mut nonce_pool = frost::round1::NoncePool::new(vec![nonces.clone()]);
+    nonces.abort(Some(&mut nonce_pool));
+    assert!(nonce_pool.is_empty());
+    assert_eq!(
+        frost::round2::sign_preprocessed(&signing_package, &mut nonce_pool, key_package),
+        Err(Error::IncorrectCommitment)
+    );
+
+    // The other signer's nonces are unaffected.
+    let (identifier, nonces) = nonces_map.pop_first().unwrap();
+    frost::round2::sign(&signing_package, &nonces, &key_packages[&identifier]).unwrap();
+}
+
+/// Test that substituted signing commitments are detected with
+/// [`frost::SigningPackage::verify_signed_commitments`].
+pub fn check_signed_commitments<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut commitments_map = BTreeMap::new();
+    let mut signed_map = BTreeMap::new();
+    for (identifier, key_package) in key_packages.iter().take(2) {
+        let (_nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        signed_map.insert(*identifier, commitments.signed(key_package, &mut rng));
+        commitments_map.insert(*identifier, commitments);
+    }
+    let message = b"message to sign";
+    let signing_package = frost::SigningPackage::new(commitments_map.clone(), message);
+    signing_package
+        .verify_signed_commitments(&signed_map, &pubkeys)
+        .unwrap();
+
+    let (culprit, key_package) = key_packages.iter().next().unwrap();
+    let (_nonces, substituted) = frost::round1::commit(key_package.signing_share(), &mut rng);
+    let mut substituted_map = commitments_map.clone();
+    substituted_map.insert(*culprit, substituted);
+    let substituted_package = frost::SigningPackage::new(substituted_map, message);
+
+    // A substituted commitment doesn't match the signed one.
+    assert_eq!(
+        substituted_package.verify_signed_commitments(&signed_map, &pubkeys),
+        Err(Error::InvalidCommitmentSignature { culprit: *culprit })
+    );
+
+    // A commitment without a signature is rejected.
+    let mut unsigned_map = signed_map.clone();
+    unsigned_map.remove(culprit);
+    assert_eq!(
+        signing_package.verify_signed_commitments(&unsigned_map, &pubkeys),
+        Err(Error::InvalidCommitmentSignature { culprit: *culprit })
+    );
+
+    // A commitment signed by someone else is rejected.
+    let (_, other_key_package) = key_packages.iter().nth(2).unwrap();
+    let mut wrongly_signed_map = signed_map.clone();
+    wrongly_signed_map.insert(*culprit, substituted.signed(other_key_package, &mut rng));
+    assert_eq!(
+        substituted_package.verify_signed_commitments(&wrongly_signed_map, &pubkeys),
+        Err(Error::InvalidCommitmentSignature { culprit: *culprit })
+    );
+
+    // A signature over other commitments is rejected.
+    let signed =
+        frost::round1::SignedCommitments::new(substituted, *signed_map[culprit].signature());
+    wrongly_signed_map.insert(*culprit, signed);
+    assert_eq!(
+        substituted_package.verify_signed_commitments(&wrongly_signed_map, &pubkeys),
+        Err(Error::InvalidCommitmentSignature { culprit: *culprit })
+    );
+}
+
+/// Test that signing and aggregation reject nonce commitments shared between
+/// signers.
+pub fn check_colliding_commitments<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let ids: Vec<_> = commitments_map.keys().copied().collect();
+    let (first, second, third) = (ids[0], ids[1], ids[2]);
+    let (signer, nonces) = nonces_map.first_key_value().unwrap();
+
+    // Give the third signer the hiding commitment of the first one, and the
+    // binding commitment of the second one.
+    for (other, collision) in [
+        (
+            first,
+            frost::round1::SigningCommitments::new(
+                *commitments_map[&first].hiding(),
+                *commitments_map[&third].binding(),
+            ),
+        ),
+        (
+            second,
+            frost::round1::SigningCommitments::new(
+                *commitments_map[&third].hiding(),
+                *commitments_map[&second].binding(),
+            ),
+        ),
+    ] {
+        let mut colliding_map = commitments_map.clone();
+        colliding_map.insert(third, collision);
+        let signing_package = frost::SigningPackage::new(colliding_map, b"message to sign");
+        let expected = Error::CollidingCommitments {
+            first: other,
+            second: third,
+        };
+
+        assert_eq!(signing_package.validate(), Err(expected));
+        assert_eq!(
+            frost::round2::sign(&signing_package, nonces, &key_packages[signer]),
+            Err(expected)
+        );
+
+        // Placeholder shares, since the signing package can't be signed.
+        let one = <<C::Group as Group>::Field>::one();
+        let share = frost::round2::SignatureShare::<C>::deserialize(
+            <<C::Group as Group>::Field>::serialize(&one),
+        )
+        .unwrap();
+        let signature_shares = key_packages.keys().map(|id| (*id, share)).collect();
+        assert_eq!(
+            frost::aggregate(&signing_package, &signature_shares, &pubkeys),
+            Err(expected)
+        );
+    }
+
+    // The original commitments don't collide.
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+    signing_package.validate().unwrap();
+    frost::round2::sign(&signing_package, nonces, &key_packages[signer]).unwrap();
+}
+
+/// Test that signature shares bound to a signing session only aggregate in
+/// that session.
+pub fn check_sign_with_session_id<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+    let session_a = [0xaa; 32];
+    let session_b = [0xbb; 32];
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(2) {
+        let (nonces, commitments) = frost::round1::commit_with_session_id(
+            key_package.signing_share(),
+            &session_a,
+            &mut rng,
+        );
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let message = b"message to sign";
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let share = frost::round2::sign_with_session_id(
+                &signing_package,
+                nonces,
+                &key_packages[id],
+                &session_a,
+            )
+            .unwrap();
+            (*id, share)
+        })
+        .collect();
+
+    let signature =
+        frost::aggregate_with_session_id(&signing_package, &signature_shares, &pubkeys, &session_a)
+            .unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+
+    // The shares are invalid in another session, or without a session.
+    assert!(frost::aggregate_with_session_id(
+        &signing_package,
+        &signature_shares,
+        &pubkeys,
+        &session_b
+    )
+    .is_err());
+    assert!(frost::aggregate(&signing_package, &signature_shares, &pubkeys).is_err());
+
+    // The shares verify individually, in a batch and with a proof only for
+    // the same session.
+    assert!(
+        frost::aggregate_commitment_with_session_id(
+            &signing_package,
+            pubkeys.verifying_key(),
+            &session_a
+        )
+        .unwrap()
+            == signature.R
+    );
+    frost::round2::batch_verify_shares_with_session_id(
+        &signing_package,
+        &signature_shares,
+        &pubkeys,
+        &session_a,
+        &mut rng,
+    )
+    .unwrap();
+    assert!(frost::round2::batch_verify_shares_with_session_id(
+        &signing_package,
+        &signature_shares,
+        &pubkeys,
+        &session_b,
+        &mut rng,
+    )
+    .is_err());
+    for (id, share) in &signature_shares {
+        let verifying_share = &pubkeys.verifying_shares()[id];
+        frost::round2::check_share_with_session_id(
+            share,
+            *id,
+            &signing_package,
+            verifying_share,
+            pubkeys.verifying_key(),
+            &session_a,
+        )
+        .unwrap();
+        assert_eq!(
+            frost::round2::check_share_with_session_id(
+                share,
+                *id,
+                &signing_package,
+                verifying_share,
+                pubkeys.verifying_key(),
+                &session_b,
+            ),
+            Err(Error::InvalidSignatureShare { culprit: *id })
+        );
+
+        frost::round2::SignatureShare::with_proof_and_session_id(
+            &signing_package,
+            pubkeys.verifying_key(),
+            *id,
+            *share,
+            &session_a,
+        )
+        .unwrap()
+        .verify_with_signing_package_and_session_id(
+            &signing_package,
+            verifying_share,
+            pubkeys.verifying_key(),
+            &session_a,
+        )
+        .unwrap();
+        assert!(frost::round2::SignatureShare::with_proof_and_session_id(
+            &signing_package,
+            pubkeys.verifying_key(),
+            *id,
+            *share,
+            &session_b,
+        )
+        .unwrap()
+        .verify_with_signing_package_and_session_id(
+            &signing_package,
+            verifying_share,
+            pubkeys.verifying_key(),
+            &session_a,
+        )
+        .is_err());
+    }
+}
+
+/// Test that secret sharing commitments with the wrong number of coefficients
+/// are rejected when verifying dealer shares and in the DKG.
+pub fn check_vss_commitment_threshold<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let share = shares.values().next().unwrap();
+    assert_eq!(share.commitment().threshold(), 3);
+    share.verify_with_min_signers(3).unwrap();
+    assert_eq!(
+        share.verify_with_min_signers(2),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+
+    // A commitment of lower degree than agreed is detected, even though the
+    // share itself may be consistent with it.
+    let coefficients = share.commitment().coefficients();
+    let truncated = frost::keys::SecretShare::new(
+        *share.identifier(),
+        *share.signing_share(),
+        frost::keys::VerifiableSecretSharingCommitment::new(coefficients[..2].to_vec()),
+    );
+    assert_eq!(truncated.commitment().threshold(), 2);
+    assert_eq!(
+        truncated.verify_with_min_signers(3),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+
+    // In the DKG, a round 1 package with an extra coefficient is rejected.
+    let mut secret_packages = BTreeMap::new();
+    let mut round1_packages = BTreeMap::new();
+    for n in 1..=3u16 {
+        let identifier: frost::Identifier<C> = n.try_into().unwrap();
+        let (secret_package, package) =
+            frost::keys::dkg::part1(identifier, 3, 2, &mut rng).unwrap();
+        secret_packages.insert(identifier, secret_package);
+        round1_packages.insert(identifier, package);
+    }
+    let (receiver, secret_package) = secret_packages.pop_first().unwrap();
+    let mut received: BTreeMap<_, _> = round1_packages
+        .into_iter()
+        .filter(|(id, _)| *id != receiver)
+        .collect();
+    frost::keys::dkg::part2(secret_package.clone(), &received).unwrap();
+
+    let (sender, package) = received.pop_first().unwrap();
+    let mut coefficients = package.commitment().coefficients().to_vec();
+    coefficients.push(*coefficients.last().unwrap());
+    let commitment = frost::keys::VerifiableSecretSharingCommitment::new(coefficients);
+    assert_eq!(commitment.threshold(), 3);
+    received.insert(
+        sender,
+        frost::keys::dkg::round1::Package::new(commitment, *package.proof_of_knowledge()),
+    );
+    assert_eq!(
+        frost::keys::dkg::part2(secret_package, &received).map(|_| ()),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+}
+
+/// Test verifying a pair of signatures over the same message.
+pub fn check_verify_pair<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let msg = b"message to attest";
+    let key_a = frost::SigningKey::<C>::new(&mut rng);
+    let key_b = frost::SigningKey::<C>::new(&mut rng);
+    let sig_a = key_a.sign(&mut rng, msg);
+    let sig_b = key_b.sign(&mut rng, msg);
+    let vk_a = frost::VerifyingKey::from(&key_a);
+    let vk_b = frost::VerifyingKey::from(&key_b);
+    let invalid_a = key_a.sign(&mut rng, b"other message");
+    let invalid_b = key_b.sign(&mut rng, b"other message");
+
+    for (sig_a, sig_b, expected) in [
+        (&sig_a, &sig_b, (true, true)),
+        (&invalid_a, &sig_b, (false, true)),
+        (&sig_a, &invalid_b, (true, false)),
+        (&invalid_a, &invalid_b, (false, false)),
+    ] {
+        let (result_a, result_b) =
+            frost::Signature::verify_pair(msg, sig_a, &vk_a, sig_b, &vk_b, &mut rng);
+        assert_eq!((result_a.is_ok(), result_b.is_ok()), expected);
+    }
+
+    // Swapping the keys makes both signatures invalid.
+    let (result_a, result_b) =
+        frost::Signature::verify_pair(msg, &sig_a, &vk_b, &sig_b, &vk_a, &mut rng);
+    assert_eq!(result_a, Err(Error::InvalidSignature));
+    assert_eq!(result_b, Err(Error::InvalidSignature));
+}
+
+/// Test evaluating the secret polynomial at arbitrary points from a quorum of
+/// key packages.
+pub fn check_evaluate_at<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, _pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: Vec<_> = frost::keys::key_packages::<C>(&shares)
+        .unwrap()
+        .into_values()
+        .collect();
+    let quorum = &key_packages[..3];
+
+    // Evaluating at a participant's identifier yields their share, whether
+    // or not they are part of the quorum.
+    for key_package in &key_packages {
+        let point = *key_package.identifier() * <<C::Group as Group>::Field>::one();
+        assert_eq!(
+            frost::keys::evaluate_at(quorum, point).unwrap(),
+            *key_package.signing_share()
+        );
+    }
+
+    // Evaluating at zero yields the secret.
+    let zero = <<C::Group as Group>::Field>::zero();
+    let secret = frost::keys::reconstruct(quorum).unwrap();
+    assert_eq!(
+        frost::keys::evaluate_at(quorum, zero)
+            .unwrap()
+            .serialize()
+            .as_ref(),
+        secret.serialize().as_ref()
+    );
+
+    // Any quorum evaluates to the same value at a new point.
+    let point = <<C::Group as Group>::Field>::random(&mut rng);
+    assert_eq!(
+        frost::keys::evaluate_at(quorum, point).unwrap(),
+        frost::keys::evaluate_at(&key_packages[2..], point).unwrap()
+    );
+
+    assert_eq!(
+        frost::keys::evaluate_at::<C>(&[], point),
+        Err(Error::IncorrectNumberOfShares)
+    );
+    assert_eq!(
+        frost::keys::evaluate_at(
+            &[quorum[0].clone(), quorum[1].clone(), quorum[0].clone()],
+            point
+        ),
+        Err(Error::DuplicatedIdentifier)
+    );
+}
+
+/// Test that [`frost::aggregate_instrumented`] reports the expected phases.
+pub fn check_aggregate_instrumented<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(3) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+    let mut signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+            (*id, share)
+        })
+        .collect();
+
+    let mut phases = vec![];
+    let signature =
+        frost::aggregate_instrumented(&signing_package, &signature_shares, &pubkeys, |phase, _| {
+            phases.push(phase.to_string())
+        })
+        .unwrap();
+    assert_eq!(
+        signature,
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap()
+    );
+    assert_eq!(phases, ["group_commitment", "summation", "verification"]);
+
+    // Share verification is only reported if the aggregate signature is
+    // invalid.
+    let (culprit, share) = signature_shares.pop_first().unwrap();
+    let one = <<C::Group as Group>::Field>::one();
+    let tampered = frost::round2::SignatureShare::<C>::deserialize(
+        <<C::Group as Group>::Field>::serialize(&(share.share + one)),
+    )
+    .unwrap();
+    signature_shares.insert(culprit, tampered);
+
+    let mut phases = vec![];
+    let result =
+        frost::aggregate_instrumented(&signing_package, &signature_shares, &pubkeys, |phase, _| {
+            phases.push(phase.to_string())
+        });
+    #[cfg(feature = "cheater-detection")]
+    {
+        assert_eq!(result, Err(Error::InvalidSignatureShare { culprit }));
+        assert_eq!(
+            phases,
+            [
+                "group_commitment",
+                "summation",
+                "verification",
+                "share_verification"
+            ]
+        );
+    }
+    #[cfg(not(feature = "cheater-detection"))]
+    {
+        assert_eq!(result, Err(Error::InvalidSignature));
+        assert_eq!(phases, ["group_commitment", "summation", "verification"]);
+    }
+}
+
+/// Test restricting a public key package to a subset of the signers.
+pub fn check_restrict_public_key_package<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+    let subset: BTreeSet<_> = key_packages.keys().skip(2).copied().collect();
+
+    let restricted = pubkeys.restrict_to(&subset).unwrap();
+    assert_eq!(restricted.max_signers(), 3);
+    assert_eq!(restricted.min_signers(), pubkeys.min_signers());
+    assert_eq!(restricted.verifying_key(), pubkeys.verifying_key());
+    assert!(restricted.verifying_shares().keys().eq(subset.iter()));
+
+    // The restricted package can be used to aggregate shares of the subset.
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for id in &subset {
+        let (nonces, commitments) =
+            frost::round1::commit(key_packages[id].signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let message = b"message to sign";
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    let signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+            (*id, share)
+        })
+        .collect();
+    let signature = frost::aggregate(&signing_package, &signature_shares, &restricted).unwrap();
+    restricted
+        .verifying_key()
+        .verify(message, &signature)
+        .unwrap();
+
+    // Unknown signers and too small subsets are rejected.
+    let unknown: frost::Identifier<C> = 6u16.try_into().unwrap();
+    let mut with_unknown = subset.clone();
+    with_unknown.insert(unknown);
+    assert_eq!(
+        pubkeys.restrict_to(&with_unknown),
+        Err(Error::UnknownIdentifier)
+    );
+    let too_small: BTreeSet<_> = subset.iter().take(2).copied().collect();
+    assert_eq!(
+        pubkeys.restrict_to(&too_small),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+}
+
+/// Test checking a reconstructed signing key against the group verifying key.
+pub fn check_signing_key_matches<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: Vec<_> = frost::keys::key_packages::<C>(&shares)
+        .unwrap()
+        .into_values()
+        .collect();
+
+    let signing_key = frost::keys::reconstruct(&key_packages[..3]).unwrap();
+    assert!(signing_key.matches(pubkeys.verifying_key()));
+
+    let one = <<C::Group as Group>::Field>::one();
+    let wrong_key = frost::SigningKey::<C>::from_scalar(signing_key.to_scalar() + one);
+    assert!(!wrong_key.matches(pubkeys.verifying_key()));
+
+    // Too few shares reconstruct a wrong key.
+    let mut too_few = key_packages[..2].to_vec();
+    for key_package in &mut too_few {
+        key_package.min_signers = 2;
+    }
+    let wrong_key = frost::keys::reconstruct(&too_few).unwrap();
+    assert!(!wrong_key.matches(pubkeys.verifying_key()));
+}
+
+/// Test serializing signature shares together with their identifier.
+pub fn check_signature_share_with_identifier<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, _pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(2) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    for (id, nonces) in &nonces_map {
+        let share = frost::round2::sign(&signing_package, nonces, &key_packages[id]).unwrap();
+        let bytes = share.serialize_with_identifier(*id);
+        assert_eq!(
+            frost::round2::SignatureShare::deserialize_with_identifier(&bytes).unwrap(),
+            (*id, share)
+        );
+
+        // Truncated or extended encodings are rejected.
+        assert_eq!(
+            frost::round2::SignatureShare::<C>::deserialize_with_identifier(
+                &bytes[..bytes.len() - 1]
+            ),
+            Err(Error::DeserializationError)
+        );
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert_eq!(
+            frost::round2::SignatureShare::<C>::deserialize_with_identifier(&extended),
+            Err(Error::DeserializationError)
+        );
+    }
+
+    // A zero identifier is rejected.
+    let share = frost::round2::SignatureShare::<C>::deserialize(
+        <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::one()),
+    )
+    .unwrap();
+    let mut bytes = <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero())
+        .as_ref()
+        .to_vec();
+    bytes.extend_from_slice(share.serialize().as_ref());
+    assert!(frost::round2::SignatureShare::<C>::deserialize_with_identifier(&bytes).is_err());
+}
+
+/// Test that aggregating fewer than `min_signers` signature shares fails.
+pub fn check_aggregate_insufficient_shares<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages = frost::keys::key_packages::<C>(&shares).unwrap();
+
+    // Sign with one fewer than the threshold. Signers refuse to sign with too
+    // few commitments, so lower the threshold in their key packages to get the
+    // shares.
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (id, key_package) in key_packages.iter().take(2) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*id, nonces);
+        commitments_map.insert(*id, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+    let signature_shares: BTreeMap<_, _> = nonces_map
+        .iter()
+        .map(|(id, nonces)| {
+            let mut key_package = key_packages[id].clone();
+            key_package.min_signers = 2;
+            let share = frost::round2::sign(&signing_package, nonces, &key_package).unwrap();
+            (*id, share)
+        })
+        .collect();
+
+    assert_eq!(pubkeys.min_signers(), Some(3));
+    assert_eq!(
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys),
+        Err(Error::InsufficientShares)
+    );
+
+    // A package created with `PublicKeyPackage::new` doesn't record the
+    // threshold, so aggregation proceeds and the signature fails to verify.
+    let pubkeys = frost::keys::PublicKeyPackage::new(
+        pubkeys.verifying_shares().clone(),
+        *pubkeys.verifying_key(),
+    );
+    assert_eq!(pubkeys.min_signers(), None);
+    assert_eq!(
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys),
+        Err(Error::InvalidSignature)
+    );
+}
@@ -10,7 +10,10 @@ use crate as frost;
 use crate::{
     compute_lagrange_coefficient,
     keys::{
-        repairable::{repair_share_step_1, repair_share_step_2, repair_share_step_3},
+        repairable::{
+            repair_share_delta_commitments, repair_share_robust, repair_share_step_1,
+            repair_share_step_2, repair_share_step_3,
+        },
         PublicKeyPackage, SecretShare, SigningShare,
     },
     Ciphersuite, Error, Field, Group, Identifier, Scalar,
@@ -240,3 +243,116 @@ pub fn check_repair_share_step_1_fails_with_invalid_min_signers<
     assert!(out.is_err());
     assert!(out == Err(Error::InvalidMinSigners))
 }
+
+/// Test repairing a share with more helpers than needed, one of which is
+/// dishonest, and identifying them.
+pub fn check_repair_share_robust<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys): (BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>) =
+        frost::keys::generate_with_dealer(
+            max_signers,
+            min_signers,
+            frost::keys::IdentifierList::Default,
+            &mut rng,
+        )
+        .unwrap();
+
+    // Signer 2 will lose their share
+    // Signers (helpers) 1, 3, 4 and 5 will help signer 2 (participant) to recover their share
+    let participant = &shares[&Identifier::try_from(2).unwrap()];
+    let helpers: Vec<Identifier<C>> = [1u16, 3, 4, 5]
+        .into_iter()
+        .map(|i| Identifier::try_from(i).unwrap())
+        .collect();
+
+    let deltas: BTreeMap<_, _> = helpers
+        .iter()
+        .map(|helper_i| {
+            let deltas_i = repair_share_step_1(
+                &helpers,
+                &shares[helper_i],
+                &mut rng,
+                participant.identifier,
+            )
+            .unwrap();
+            (*helper_i, deltas_i)
+        })
+        .collect();
+    let delta_commitments: BTreeMap<_, _> = deltas
+        .iter()
+        .map(|(helper_i, deltas_i)| (*helper_i, repair_share_delta_commitments(deltas_i)))
+        .collect();
+    let sigmas: BTreeMap<_, _> = helpers
+        .iter()
+        .map(|helper_j| {
+            let deltas_j: Vec<_> = deltas.values().map(|deltas_i| deltas_i[helper_j]).collect();
+            (*helper_j, repair_share_step_2::<C>(&deltas_j))
+        })
+        .collect();
+
+    let repaired = repair_share_robust(
+        &sigmas,
+        &delta_commitments,
+        &pubkeys,
+        participant.identifier,
+        &participant.commitment,
+    )
+    .unwrap();
+    assert!(repaired.signing_share == participant.signing_share);
+
+    // Helper 4 sends a wrong sigma.
+    let cheater = helpers[2];
+    let one = <<C::Group as Group>::Field>::one();
+    let mut bad_sigmas = sigmas.clone();
+    *bad_sigmas.get_mut(&cheater).unwrap() = sigmas[&cheater] + one;
+    let err = repair_share_robust(
+        &bad_sigmas,
+        &delta_commitments,
+        &pubkeys,
+        participant.identifier,
+        &participant.commitment,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidParticipantSecretShare { culprit: cheater }
+    );
+
+    // Helper 4 generates deltas which do not add up to their share, and sends
+    // commitments consistent with them.
+    let mut bad_deltas = deltas.clone();
+    let bad_delta = bad_deltas
+        .get_mut(&cheater)
+        .unwrap()
+        .get_mut(&helpers[0])
+        .unwrap();
+    *bad_delta = *bad_delta + one;
+    let mut bad_delta_commitments = delta_commitments.clone();
+    bad_delta_commitments.insert(
+        cheater,
+        repair_share_delta_commitments(&bad_deltas[&cheater]),
+    );
+    let bad_sigmas: BTreeMap<_, _> = helpers
+        .iter()
+        .map(|helper_j| {
+            let deltas_j: Vec<_> = bad_deltas
+                .values()
+                .map(|deltas_i| deltas_i[helper_j])
+                .collect();
+            (*helper_j, repair_share_step_2::<C>(&deltas_j))
+        })
+        .collect();
+    let err = repair_share_robust(
+        &bad_sigmas,
+        &bad_delta_commitments,
+        &pubkeys,
+        participant.identifier,
+        &participant.commitment,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidParticipantSecretShare { culprit: cheater }
+    );
+}
@@ -91,6 +91,7 @@ pub fn parse_test_vectors_dkg<C: Ciphersuite>(json_vectors: &Value) -> DKGTestVe
         verifying_share,
         verifying_key,
         min_signers: 2,
+        capabilities: Default::default(),
     };
 
     DKGTestVectors {
@@ -177,6 +178,7 @@ fn build_public_key_package<C: Ciphersuite>(json_vectors: &Value) -> PublicKeyPa
         header: Header::default(),
         verifying_shares,
         verifying_key,
+        min_signers: Some(2),
     }
 }
 
@@ -204,6 +206,7 @@ pub fn check_dkg_keygen<C: Ciphersuite>(json_vectors: &Value) {
     .unwrap();
 
     let round1_secret_package = SecretPackage {
+        header: Header::default(),
         identifier: participant_id,
         coefficients,
         commitment: commitment.clone(),
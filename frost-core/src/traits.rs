@@ -228,4 +228,21 @@ pub trait Ciphersuite: Copy + Clone + PartialEq + Debug {
 
         public_key.verify_prehashed(c, signature)
     }
+
+    /// Return the length in bytes of a [`Self::SignatureSerialization`], i.e.
+    /// the length of a serialized [`Group::Element`] plus the length of a
+    /// serialized [`Field::Scalar`].
+    ///
+    /// This is derived from the group and field encodings, and is useful to
+    /// size buffers without hardcoding per-ciphersuite values.
+    fn signature_len() -> usize {
+        let element_len = <Self::Group>::serialize(&<Self::Group>::generator())
+            .as_ref()
+            .len();
+        let scalar_len =
+            <<Self::Group as Group>::Field>::serialize(&<<Self::Group as Group>::Field>::one())
+                .as_ref()
+                .len();
+        element_len + scalar_len
+    }
 }
@@ -1,9 +1,12 @@
-use std::fmt::{self, Debug};
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Debug},
+};
 
 #[cfg(any(test, feature = "test-impl"))]
 use hex::FromHex;
 
-use crate::{Challenge, Ciphersuite, Element, Error, Group, Signature};
+use crate::{Challenge, Ciphersuite, Element, Error, Group, Identifier, Signature, SigningPackage};
 
 #[cfg(feature = "serde")]
 use crate::serialization::ElementSerialization;
@@ -59,6 +62,8 @@ where
         challenge: Challenge<C>,
         signature: &Signature<C>,
     ) -> Result<(), Error<C>> {
+        signature.check_non_degenerate()?;
+
         // Verify check is h * ( - z * B + R  + c * A) == 0
         //                 h * ( z * B - c * A - R) == 0
         //
@@ -79,6 +84,38 @@ where
         C::verify_signature(msg, signature, self)
     }
 
+    /// Compute the residual `z * G - c * PK - R` of the verification equation
+    /// for a purported `signature` over `msg`, where `c` is the challenge
+    /// computed from `R`, this key and `msg`.
+    ///
+    /// The residual is the identity for a valid signature; for an invalid one
+    /// it can help diagnose which value is wrong. For example, if `z` is off by
+    /// `d`, the residual is `d * G`. Note that it is not multiplied by the
+    /// cofactor.
+    pub fn verification_residual(
+        &self,
+        msg: &[u8],
+        signature: &Signature<C>,
+    ) -> <C::Group as Group>::Element {
+        let challenge = crate::challenge::<C>(&signature.R, self, msg);
+
+        C::Group::generator() * signature.z - self.element * challenge.0 - signature.R
+    }
+
+    /// Verify a purported `signature` over `msg` made by this verification key
+    /// with a [`SigningPackage::new_bound_to_set`] package, for the given set of
+    /// `signers`.
+    ///
+    /// [`SigningPackage::new_bound_to_set`]: crate::SigningPackage::new_bound_to_set
+    pub fn verify_bound_to_set(
+        &self,
+        signers: &BTreeSet<Identifier<C>>,
+        msg: &[u8],
+        signature: &Signature<C>,
+    ) -> Result<(), Error<C>> {
+        self.verify(&SigningPackage::bound_message(signers, msg), signature)
+    }
+
     /// Computes the group public key given the group commitment.
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     pub(crate) fn from_commitment(
@@ -223,6 +223,15 @@ fn check_sign_with_dealer_and_identifiers() {
     >(rng);
 }
 
+#[test]
+fn check_sign_with_sparse_identifiers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_sparse_identifiers::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
 #[test]
 fn check_sign_with_missing_identifier() {
     let rng = thread_rng();
@@ -238,3 +247,480 @@ fn check_sign_with_incorrect_commitments() {
         rng,
     );
 }
+
+#[test]
+fn check_split_deterministic() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_deterministic::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_commitment_pool() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_commitment_pool::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_public_key_package_audit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_audit::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signature_share_with_proof() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_share_with_proof::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_commit_with_aux() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_commit_with_aux::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_collapse_to_single() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_collapse_to_single::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_to_group_commitment_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_to_group_commitment_share::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_check_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_check_share::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_key_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_packages::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_z_is_sum_of_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_z_is_sum_of_shares::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_with_message_digest() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_message_digest::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_commitment::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signer_limit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signer_limit::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_backend() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_backend::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_proof_of_possession() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_proof_of_possession::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_verify_rejects_degenerate_signatures() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_rejects_degenerate_signatures::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_multi_group_session() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_multi_group_session::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_split_with_custom_identifier_list() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_with_custom_identifier_list::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signing_package_equality_ignores_order() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_equality_ignores_order::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_identifier_try_from_bytes() {
+    frost_core::tests::ciphersuite_generic::check_identifier_try_from_bytes::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_public_key_package_signer_counts() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_signer_counts::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_commitments::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_reconstructed_key() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_reconstructed_key::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_minimal_quorums() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_minimal_quorums::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_missing_signers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_missing_signers::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_identifier_ordering() {
+    frost_core::tests::ciphersuite_generic::check_identifier_ordering::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_dkg_with_serialized_secret_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_dkg_with_serialized_secret_packages::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_capabilities() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_capabilities::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_preprocessed() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_preprocessed::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signing_share_entropy() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_share_entropy::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_with_details() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_details::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_single_rng_ceremony() {
+    frost_core::tests::ciphersuite_generic::check_single_rng_ceremony::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_test_dealer() {
+    frost_core::tests::ciphersuite_generic::check_test_dealer::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_aggregate_rejects_unexpected_signer() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_rejects_unexpected_signer::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregator() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregator::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_simple_sign_all() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_simple_sign_all::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_key_package_check_group() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_check_group::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_all_with_fixed_nonces() {
+    frost_core::tests::ciphersuite_generic::check_sign_all_with_fixed_nonces::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_chained_signing() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_chained_signing::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_error_codes() {
+    frost_core::tests::ciphersuite_generic::check_error_codes::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_split_with_polynomial() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_with_polynomial::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_conformance() {
+    let mut rng = thread_rng();
+
+    frost_core::conformance::run::<Ed25519Sha512, _>(&mut rng);
+}
+
+#[test]
+fn check_sign_bound_to_set() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_bound_to_set::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signature_len() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_len::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_message_limit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_message_limit::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_commit_from_seed() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_commit_from_seed::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_batch_verify_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_batch_verify_shares::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signature_framing() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_framing::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_degenerate_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_degenerate_commitment::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_group_key_from_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_key_from_packages::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_challenge() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_challenge::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_verification_residual() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verification_residual::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_participation_receipt() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_participation_receipt::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_abort_nonces() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_abort_nonces::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signed_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signed_commitments::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_colliding_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_colliding_commitments::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_session_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_session_id::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_vss_commitment_threshold() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_vss_commitment_threshold::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_verify_pair() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_pair::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_evaluate_at() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_evaluate_at::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_instrumented() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_instrumented::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_restrict_public_key_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_restrict_public_key_package::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signing_key_matches() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_key_matches::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signature_share_with_identifier() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_share_with_identifier::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_aggregate_insufficient_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_insufficient_shares::<Ed25519Sha512, _>(
+        rng,
+    );
+}
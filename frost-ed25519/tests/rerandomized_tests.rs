@@ -8,3 +8,10 @@ fn check_randomized_sign_with_dealer() {
     let (_msg, _group_signature, _group_pubkey) =
         frost_rerandomized::tests::check_randomized_sign_with_dealer::<Ed25519Sha512, _>(rng);
 }
+
+#[test]
+fn check_randomized_verify_share() {
+    let rng = thread_rng();
+
+    frost_rerandomized::tests::check_randomized_verify_share::<Ed25519Sha512, _>(rng);
+}
@@ -40,3 +40,8 @@ fn check_deserialize_identity() {
     let r = <Ed448Shake256 as Ciphersuite>::Group::deserialize(&encoded_identity);
     assert_eq!(r, Err(GroupError::InvalidIdentityElement));
 }
+
+#[test]
+fn check_deserialize_scalar_bounds() {
+    frost_core::tests::ciphersuite_generic::check_deserialize_scalar_bounds::<Ed448Shake256>();
+}
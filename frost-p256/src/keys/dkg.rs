@@ -50,9 +50,9 @@ pub fn part1<R: RngCore + CryptoRng>(
     identifier: Identifier,
     max_signers: u16,
     min_signers: u16,
-    mut rng: R,
+    rng: &mut R,
 ) -> Result<(round1::SecretPackage, round1::Package), Error> {
-    frost::keys::dkg::part1(identifier, max_signers, min_signers, &mut rng)
+    frost::keys::dkg::part1(identifier, max_signers, min_signers, rng)
 }
 
 /// Performs the second part of the distributed key generation protocol
@@ -220,6 +220,15 @@ fn check_sign_with_dealer_and_identifiers() {
     );
 }
 
+#[test]
+fn check_sign_with_sparse_identifiers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_sparse_identifiers::<P256Sha256, _>(
+        rng,
+    );
+}
+
 #[test]
 fn check_sign_with_missing_identifier() {
     let rng = thread_rng();
@@ -235,3 +244,468 @@ fn check_sign_with_incorrect_commitments() {
         rng,
     );
 }
+
+#[test]
+fn check_split_deterministic() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_deterministic::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_commitment_pool() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_commitment_pool::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_public_key_package_audit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_audit::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_signature_share_with_proof() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_share_with_proof::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_commit_with_aux() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_commit_with_aux::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_collapse_to_single() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_collapse_to_single::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_to_group_commitment_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_to_group_commitment_share::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_check_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_check_share::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_key_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_packages::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_z_is_sum_of_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_z_is_sum_of_shares::<P256Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_with_message_digest() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_message_digest::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_commitment::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_signer_limit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signer_limit::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_backend() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_backend::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_proof_of_possession() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_proof_of_possession::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_verify_rejects_degenerate_signatures() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_rejects_degenerate_signatures::<
+        P256Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_multi_group_session() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_multi_group_session::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_split_with_custom_identifier_list() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_with_custom_identifier_list::<P256Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signing_package_equality_ignores_order() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_equality_ignores_order::<
+        P256Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_identifier_try_from_bytes() {
+    frost_core::tests::ciphersuite_generic::check_identifier_try_from_bytes::<P256Sha256>();
+}
+
+#[test]
+fn check_public_key_package_signer_counts() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_signer_counts::<P256Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_verify_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_commitments::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_reconstructed_key() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_reconstructed_key::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_minimal_quorums() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_minimal_quorums::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_missing_signers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_missing_signers::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_identifier_ordering() {
+    frost_core::tests::ciphersuite_generic::check_identifier_ordering::<P256Sha256>();
+}
+
+#[test]
+fn check_dkg_with_serialized_secret_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_dkg_with_serialized_secret_packages::<
+        P256Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_capabilities() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_capabilities::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_preprocessed() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_preprocessed::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_signing_share_entropy() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_share_entropy::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_with_details() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_details::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_single_rng_ceremony() {
+    frost_core::tests::ciphersuite_generic::check_single_rng_ceremony::<P256Sha256>();
+}
+
+#[test]
+fn check_test_dealer() {
+    frost_core::tests::ciphersuite_generic::check_test_dealer::<P256Sha256>();
+}
+
+#[test]
+fn check_aggregate_rejects_unexpected_signer() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_rejects_unexpected_signer::<
+        P256Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregator() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregator::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_simple_sign_all() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_simple_sign_all::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_key_package_check_group() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_check_group::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_all_with_fixed_nonces() {
+    frost_core::tests::ciphersuite_generic::check_sign_all_with_fixed_nonces::<P256Sha256>();
+}
+
+#[test]
+fn check_chained_signing() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_chained_signing::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_error_codes() {
+    frost_core::tests::ciphersuite_generic::check_error_codes::<P256Sha256>();
+}
+
+#[test]
+fn check_split_with_polynomial() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_with_polynomial::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_conformance() {
+    let mut rng = thread_rng();
+
+    frost_core::conformance::run::<P256Sha256, _>(&mut rng);
+}
+
+#[test]
+fn check_sign_bound_to_set() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_bound_to_set::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_signature_len() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_len::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_message_limit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_message_limit::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_commit_from_seed() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_commit_from_seed::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_batch_verify_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_batch_verify_shares::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_signature_framing() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_framing::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_degenerate_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_degenerate_commitment::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_group_key_from_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_key_from_packages::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_challenge() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_challenge::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_verification_residual() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verification_residual::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_participation_receipt() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_participation_receipt::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_abort_nonces() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_abort_nonces::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_signed_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signed_commitments::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_colliding_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_colliding_commitments::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_session_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_session_id::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_vss_commitment_threshold() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_vss_commitment_threshold::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_verify_pair() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_pair::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_evaluate_at() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_evaluate_at::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_instrumented() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_instrumented::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_restrict_public_key_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_restrict_public_key_package::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_signing_key_matches() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_key_matches::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_signature_share_with_identifier() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_share_with_identifier::<P256Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_aggregate_insufficient_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_insufficient_shares::<P256Sha256, _>(
+        rng,
+    );
+}
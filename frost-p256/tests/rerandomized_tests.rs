@@ -8,3 +8,10 @@ fn check_randomized_sign_with_dealer() {
     let (_msg, _group_signature, _group_pubkey) =
         frost_rerandomized::tests::check_randomized_sign_with_dealer::<P256Sha256, _>(rng);
 }
+
+#[test]
+fn check_randomized_verify_share() {
+    let rng = thread_rng();
+
+    frost_rerandomized::tests::check_randomized_verify_share::<P256Sha256, _>(rng);
+}
@@ -77,7 +77,8 @@ impl<C: Ciphersuite> Randomize<C> for KeyPackage<C> {
             randomized_verifying_share,
             randomized_params.randomized_verifying_key,
             *self.min_signers(),
-        );
+        )
+        .with_capabilities(*self.capabilities());
         Ok(randomized_key_package)
     }
 }
@@ -106,9 +107,10 @@ impl<C: Ciphersuite> Randomize<C> for PublicKeyPackage<C> {
             })
             .collect();
 
-        Ok(PublicKeyPackage::new(
+        Ok(PublicKeyPackage::new_internal(
             randomized_verifying_shares,
             randomized_params.randomized_verifying_key,
+            self.min_signers(),
         ))
     }
 }
@@ -151,6 +153,35 @@ where
     )
 }
 
+/// Verify a single participant's re-randomized signature share, using the
+/// given [`RandomizedParams`].
+///
+/// The participant's `verifying_share` is randomized the same way as in
+/// [`sign`], and the share is checked against the randomized group verifying
+/// key. See [`frost::round2::check_share`] for documentation on the other
+/// parameters.
+pub fn verify_share<C>(
+    identifier: frost::Identifier<C>,
+    signature_share: &frost::round2::SignatureShare<C>,
+    signing_package: &frost::SigningPackage<C>,
+    verifying_share: &VerifyingShare<C>,
+    randomized_params: &RandomizedParams<C>,
+) -> Result<(), Error<C>>
+where
+    C: Ciphersuite,
+{
+    let randomized_verifying_share = VerifyingShare::<C>::new(
+        verifying_share.to_element() + randomized_params.randomizer_element,
+    );
+    frost::round2::check_share(
+        signature_share,
+        identifier,
+        signing_package,
+        &randomized_verifying_share,
+        &randomized_params.randomized_verifying_key,
+    )
+}
+
 /// A randomizer. A random scalar which is used to randomize the key.
 #[derive(Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -170,10 +201,10 @@ where
     /// current FROST signing run. It is hashed into the randomizer calculation,
     /// which binds it to that specific package.
     pub fn new<R: RngCore + CryptoRng>(
-        mut rng: R,
+        rng: &mut R,
         signing_package: &SigningPackage<C>,
     ) -> Result<Self, Error<C>> {
-        let rng_randomizer = <<C::Group as Group>::Field as Field>::random(&mut rng);
+        let rng_randomizer = <<C::Group as Group>::Field as Field>::random(rng);
         Self::from_randomizer_and_signing_package(rng_randomizer, signing_package)
     }
 
@@ -269,7 +300,7 @@ where
     pub fn new<R: RngCore + CryptoRng>(
         group_verifying_key: &VerifyingKey<C>,
         signing_package: &SigningPackage<C>,
-        rng: R,
+        rng: &mut R,
     ) -> Result<Self, Error<C>> {
         Ok(Self::from_randomizer(
             group_verifying_key,
@@ -173,3 +173,61 @@ fn check_from_randomizer_and_signing_package<C: RandomizedCiphersuite, R: RngCor
     // Make sure that different packages lead to different randomizers
     assert!(randomizer1 != randomizer2);
 }
+
+/// Test that [`crate::verify_share`] accepts a valid re-randomized signature
+/// share and rejects one checked with the wrong randomizer.
+pub fn check_randomized_verify_share<C: RandomizedCiphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonce, commitment) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces.insert(*identifier, nonce);
+        commitments.insert(*identifier, commitment);
+    }
+    let signing_package = frost::SigningPackage::new(commitments, b"message to sign");
+
+    let randomizer_params =
+        RandomizedParams::new(pubkeys.verifying_key(), &signing_package, &mut rng).unwrap();
+    let other_params =
+        RandomizedParams::new(pubkeys.verifying_key(), &signing_package, &mut rng).unwrap();
+
+    for (identifier, key_package) in &key_packages {
+        let signature_share = crate::sign(
+            &signing_package,
+            &nonces[identifier],
+            key_package,
+            *randomizer_params.randomizer(),
+        )
+        .unwrap();
+        let verifying_share = &pubkeys.verifying_shares()[identifier];
+
+        crate::verify_share(
+            *identifier,
+            &signature_share,
+            &signing_package,
+            verifying_share,
+            &randomizer_params,
+        )
+        .unwrap();
+        assert_eq!(
+            crate::verify_share(
+                *identifier,
+                &signature_share,
+                &signing_package,
+                verifying_share,
+                &other_params,
+            ),
+            Err(frost::Error::InvalidSignatureShare {
+                culprit: *identifier
+            })
+        );
+    }
+}
@@ -15,9 +15,20 @@ fn bench_ristretto255_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Ristretto255Sha512, _>(c, "ristretto255", &mut rng);
 }
 
+fn bench_ristretto255_batch_verify_shares(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_batch_verify_shares::<Ristretto255Sha512, _>(
+        c,
+        "ristretto255",
+        &mut rng,
+    );
+}
+
 criterion_group!(
     benches,
     bench_ristretto255_batch_verify,
-    bench_ristretto255_sign
+    bench_ristretto255_sign,
+    bench_ristretto255_batch_verify_shares
 );
 criterion_main!(benches);
@@ -123,7 +123,7 @@ fn hash_to_array(inputs: &[&[u8]]) -> [u8; 64] {
         h.update(i);
     }
     let mut output = [0u8; 64];
-    output.copy_from_slice(h.finalize().as_slice());
+    output.copy_from_slice(&h.finalize());
     output
 }
 
@@ -225,9 +225,20 @@ pub mod keys {
         max_signers: u16,
         min_signers: u16,
         identifiers: IdentifierList,
-        mut rng: RNG,
+        rng: &mut RNG,
     ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
-        frost::keys::generate_with_dealer(max_signers, min_signers, identifiers, &mut rng)
+        frost::keys::generate_with_dealer(max_signers, min_signers, identifiers, rng)
+    }
+
+    /// Generate a known key set with [`generate_with_dealer`], using the
+    /// default identifiers and an RNG with a fixed seed. Only meant for tests
+    /// and demos.
+    #[cfg(feature = "test-vectors")]
+    pub fn test_dealer(
+        max_signers: u16,
+        min_signers: u16,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::test_dealer(max_signers, min_signers)
     }
 
     /// Splits an existing key into FROST shares.
@@ -246,6 +257,66 @@ pub mod keys {
         frost::keys::split(secret, max_signers, min_signers, identifiers, rng)
     }
 
+    /// Splits a key into FROST shares by evaluating the polynomial with the
+    /// given coefficients, where `coefficients[0]` is the secret key. Only
+    /// meant for known-answer or adversarial test cases.
+    pub fn split_with_polynomial(
+        coefficients: &[frost::Scalar<R>],
+        max_signers: u16,
+        min_signers: u16,
+        identifiers: IdentifierList,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::split_with_polynomial(coefficients, max_signers, min_signers, identifiers)
+    }
+
+    /// Splits an existing key into FROST shares, deriving the secret polynomial
+    /// from the given `seed` instead of a caller-provided RNG.
+    ///
+    /// This is identical to [`split`] but the same inputs always produce the
+    /// same shares, which is useful for reproducible ceremonies.
+    ///
+    /// # Security
+    ///
+    /// The seed is as sensitive as the key itself: anyone who learns the seed
+    /// and a single share can recover the key. It MUST be generated uniformly
+    /// at random, kept secret, and never reused for splitting another key.
+    pub fn split_deterministic(
+        secret: &SigningKey,
+        max_signers: u16,
+        min_signers: u16,
+        identifiers: IdentifierList,
+        seed: [u8; 32],
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::split_deterministic(secret, max_signers, min_signers, identifiers, seed)
+    }
+
+    /// Verify all the secret shares generated by a dealer and convert them
+    /// into [`KeyPackage`]s, returning an error naming the offending
+    /// identifier if any share is invalid.
+    pub fn key_packages(
+        shares: &BTreeMap<Identifier, SecretShare>,
+    ) -> Result<BTreeMap<Identifier, KeyPackage>, Error> {
+        frost::keys::key_packages(shares)
+    }
+
+    /// Compute a proof that the holder of the given [`KeyPackage`] possesses
+    /// its signing share.
+    pub fn proof_of_possession<RNG: RngCore + CryptoRng>(
+        key_package: &KeyPackage,
+        rng: &mut RNG,
+    ) -> Signature {
+        frost::keys::proof_of_possession(key_package, rng)
+    }
+
+    /// Verify a proof of possession computed with [`proof_of_possession`].
+    pub fn verify_proof_of_possession(
+        verifying_share: &VerifyingShare,
+        identifier: Identifier,
+        proof_of_possession: &Signature,
+    ) -> Result<(), Error> {
+        frost::keys::verify_proof_of_possession(verifying_share, identifier, proof_of_possession)
+    }
+
     /// Recompute the secret from t-of-n secret shares using Lagrange interpolation.
     ///
     /// This can be used if for some reason the original key must be restored; e.g.
@@ -261,6 +332,45 @@ pub mod keys {
         frost::keys::reconstruct(secret_shares)
     }
 
+    /// Check whether the given key packages are sufficient to [`reconstruct`]
+    /// the secret (at least `min_signers` packages with distinct identifiers),
+    /// without actually reconstructing it.
+    pub fn can_reconstruct(key_packages: &[KeyPackage], min_signers: u16) -> Result<(), Error> {
+        frost::keys::can_reconstruct(key_packages, min_signers)
+    }
+
+    /// Evaluate the secret polynomial at `point` from at least `min_signers`
+    /// key packages using Lagrange interpolation, e.g. to compute the signing
+    /// share of a participant without reconstructing the secret.
+    pub fn evaluate_at(
+        key_packages: &[KeyPackage],
+        point: frost::Scalar<R>,
+    ) -> Result<SigningShare, Error> {
+        frost::keys::evaluate_at(key_packages, point)
+    }
+
+    /// Recompute the group verifying key from the public parts of at least
+    /// `min_signers` participants' key packages, using Lagrange interpolation
+    /// over their verifying shares.
+    pub fn group_key_from_packages(
+        packages: &[PublicKeyComponent],
+        min_signers: u16,
+    ) -> Result<VerifyingKey, Error> {
+        frost::keys::group_key_from_packages(packages, min_signers)
+    }
+
+    /// Collapse a threshold key back into a single [`SigningKey`], requiring
+    /// the key packages of all participants listed in `pubkey_package`.
+    ///
+    /// Whoever holds the returned key can sign on their own; all participants
+    /// must trust the party performing the reconstruction.
+    pub fn collapse_to_single(
+        key_packages: &[KeyPackage],
+        pubkey_package: &PublicKeyPackage,
+    ) -> Result<SigningKey, Error> {
+        frost::keys::collapse_to_single(key_packages, pubkey_package)
+    }
+
     /// Secret and public key material generated by a dealer performing
     /// [`generate_with_dealer`].
     ///
@@ -284,12 +394,29 @@ pub mod keys {
     /// [`KeyPackage`]s, which they store to later use during signing.
     pub type KeyPackage = frost::keys::KeyPackage<R>;
 
+    /// The operations a [`KeyPackage`] may be used for.
+    pub type Capabilities = frost::keys::Capabilities;
+
+    /// The public portion of a [`KeyPackage`]: everything except the secret
+    /// [`SigningShare`].
+    pub type PublicKeyComponent = frost::keys::PublicKeyComponent<R>;
+
     /// Public data that contains all the signers' public keys as well as the
     /// group public key.
     ///
     /// Used for verification purposes before publishing a signature.
     pub type PublicKeyPackage = frost::keys::PublicKeyPackage<R>;
 
+    /// The public state of a FROST group, bundled for distribution to all
+    /// nodes: the [`PublicKeyPackage`], the threshold parameters and some
+    /// metadata.
+    ///
+    /// It does not contain any secret.
+    pub type KeySet = frost::keys::KeySet<R>;
+
+    /// The magic bytes at the start of a serialized [`KeySet`].
+    pub use frost::keys::KEYSET_MAGIC;
+
     /// Contains the commitments to the coefficients for our secret polynomial _f_,
     /// used to generate participants' key shares.
     ///
@@ -327,9 +454,21 @@ pub mod round1 {
     /// SigningCommitment can be used for exactly *one* signature.
     pub type SigningCommitments = frost::round1::SigningCommitments<R>;
 
+    /// [`SigningCommitments`] signed by their signer, used to authenticate the
+    /// commitments in a [`SigningPackage`].
+    pub type SignedCommitments = frost::round1::SignedCommitments<R>;
+
     /// A commitment to a signing nonce share.
     pub type NonceCommitment = frost::round1::NonceCommitment<R>;
 
+    /// A pool of preprocessed commitments kept by the coordinator, from which
+    /// one commitment per signer is drawn for each signing operation.
+    pub type CommitmentPool = frost::round1::CommitmentPool<R>;
+
+    /// A pool of preprocessed nonces kept secret by a participant, matching the
+    /// commitments they published.
+    pub type NoncePool = frost::round1::NoncePool<R>;
+
     /// Performed once by each participant selected for the signing operation.
     ///
     /// Generates the signing nonces and commitments to be used in the signing
@@ -340,6 +479,47 @@ pub mod round1 {
     {
         frost::round1::commit::<R, RNG>(secret, rng)
     }
+
+    /// Like [`commit`], but mixes additional entropy `aux` (e.g. supplied by
+    /// an HSM) into the nonce generation, so that a partially predictable RNG
+    /// does not fully determine the nonces.
+    pub fn commit_with_aux<RNG>(
+        secret: &SigningShare,
+        aux: &[u8],
+        rng: &mut RNG,
+    ) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit_with_aux::<R, RNG>(secret, aux, rng)
+    }
+
+    /// Like [`commit`], but derives the nonces deterministically from a
+    /// per-signer `seed` and a `counter`, so that an auditor given them can
+    /// recompute the nonces of a signing session.
+    ///
+    /// The seed is as sensitive as the signing share, and a counter value
+    /// MUST NEVER be used for more than one signing with the same seed.
+    pub fn commit_from_seed(
+        secret: &SigningShare,
+        seed: [u8; 32],
+        counter: u64,
+    ) -> (SigningNonces, SigningCommitments) {
+        frost::round1::commit_from_seed::<R>(secret, seed, counter)
+    }
+
+    /// Like [`commit`], but mixes the identifier of the signing session into
+    /// the nonces, for use with [`round2::sign_with_session_id`].
+    pub fn commit_with_session_id<RNG>(
+        secret: &SigningShare,
+        session_id: &[u8; 32],
+        rng: &mut RNG,
+    ) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit_with_session_id::<R, RNG>(secret, session_id, rng)
+    }
 }
 
 /// Generated by the coordinator of the signing operation and distributed to
@@ -354,6 +534,15 @@ pub mod round2 {
     /// shares into the joint signature.
     pub type SignatureShare = frost::round2::SignatureShare<R>;
 
+    /// A signature share bundled with the data it is verified against, to be
+    /// relayed to a party holding the signing package.
+    pub type SignatureShareWithProof = frost::round2::SignatureShareWithProof<R>;
+
+    pub use frost::round2::SignerBackend;
+
+    /// A [`SignerBackend`] that holds the signing share in memory.
+    pub type SoftwareBackend = frost::round2::SoftwareBackend<R>;
+
     /// Performed once by each participant selected for the signing operation.
     ///
     /// Receives the message to be signed and a set of signing commitments and a set
@@ -362,6 +551,9 @@ pub mod round2 {
     ///
     /// Assumes the participant has already determined which nonce corresponds with
     /// the commitment that was assigned by the coordinator in the SigningPackage.
+    ///
+    /// Returns [`Error::MessageTooLong`] if the message is longer than
+    /// [`frost::DEFAULT_MESSAGE_LIMIT`] bytes.
     pub fn sign(
         signing_package: &SigningPackage,
         signer_nonces: &round1::SigningNonces,
@@ -369,6 +561,189 @@ pub mod round2 {
     ) -> Result<SignatureShare, Error> {
         frost::round2::sign(signing_package, signer_nonces, key_package)
     }
+
+    /// Like [`sign`], but returns [`Error::MessageTooLong`] if the message is
+    /// longer than `message_limit` bytes, instead of
+    /// [`frost::DEFAULT_MESSAGE_LIMIT`].
+    pub fn sign_with_message_limit(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        message_limit: usize,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_message_limit(
+            signing_package,
+            signer_nonces,
+            key_package,
+            message_limit,
+        )
+    }
+
+    /// Like [`sign`], but performs the operation that requires the signing
+    /// share through a [`SignerBackend`], so that the share can be kept e.g.
+    /// in an HSM.
+    pub fn sign_with_backend<B: SignerBackend<R>>(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        public_key_component: &keys::PublicKeyComponent,
+        backend: &B,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_backend(
+            signing_package,
+            signer_nonces,
+            public_key_component,
+            backend,
+        )
+    }
+
+    /// Like [`sign`], but binds the signature share to the signing session
+    /// identified by `session_id`. The shares must be aggregated with
+    /// [`aggregate_with_session_id`](crate::aggregate_with_session_id) for the
+    /// same session.
+    pub fn sign_with_session_id(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        session_id: &[u8; 32],
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_session_id(signing_package, signer_nonces, key_package, session_id)
+    }
+
+    /// Like [`sign`], but uses the given `challenge` instead of deriving it,
+    /// e.g. to compose FROST into a larger sigma protocol.
+    ///
+    /// The caller is responsible for the soundness of the challenge; see
+    /// [`frost::round2::sign_with_challenge`].
+    pub fn sign_with_challenge(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        challenge: frost::Scalar<R>,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_challenge(signing_package, signer_nonces, key_package, challenge)
+    }
+
+    /// Like [`sign`], but refuses to sign unless the digest of the message in
+    /// the signing package matches the `message_digest` agreed on by the
+    /// signers.
+    pub fn sign_with_message_digest(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        message_digest: &[u8],
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_message_digest(
+            signing_package,
+            signer_nonces,
+            key_package,
+            message_digest,
+        )
+    }
+
+    /// Like [`sign`], but for one-round signing: uses and removes from the
+    /// `nonce_pool` the nonces matching the commitment drawn for this
+    /// participant from a [`round1::CommitmentPool`].
+    pub fn sign_preprocessed(
+        signing_package: &SigningPackage,
+        nonce_pool: &mut round1::NoncePool,
+        key_package: &keys::KeyPackage,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_preprocessed(signing_package, nonce_pool, key_package)
+    }
+
+    /// Verify a single participant's signature share against their
+    /// verifying share, e.g. as shares arrive at the coordinator.
+    pub fn check_share(
+        signature_share: &SignatureShare,
+        identifier: Identifier,
+        signing_package: &SigningPackage,
+        verifying_share: &keys::VerifyingShare,
+        verifying_key: &VerifyingKey,
+    ) -> Result<(), Error> {
+        frost::round2::check_share(
+            signature_share,
+            identifier,
+            signing_package,
+            verifying_share,
+            verifying_key,
+        )
+    }
+
+    /// Like [`check_share`], for a signature share produced with
+    /// [`sign_with_session_id`] for the given `session_id`.
+    pub fn check_share_with_session_id(
+        signature_share: &SignatureShare,
+        identifier: Identifier,
+        signing_package: &SigningPackage,
+        verifying_share: &keys::VerifyingShare,
+        verifying_key: &VerifyingKey,
+        session_id: &[u8; 32],
+    ) -> Result<(), Error> {
+        frost::round2::check_share_with_session_id(
+            signature_share,
+            identifier,
+            signing_package,
+            verifying_share,
+            verifying_key,
+            session_id,
+        )
+    }
+
+    /// Verify all the signature shares of a signing session at once, with a
+    /// single multi-scalar multiplication, identifying the culprit if the
+    /// combined check fails.
+    pub fn batch_verify_shares<RNG: RngCore + CryptoRng>(
+        signing_package: &SigningPackage,
+        signature_shares: &BTreeMap<Identifier, SignatureShare>,
+        pubkeys: &keys::PublicKeyPackage,
+        rng: &mut RNG,
+    ) -> Result<(), Error> {
+        frost::round2::batch_verify_shares(signing_package, signature_shares, pubkeys, rng)
+    }
+
+    /// Like [`batch_verify_shares`], for signature shares produced with
+    /// [`sign_with_session_id`] for the given `session_id`.
+    pub fn batch_verify_shares_with_session_id<RNG: RngCore + CryptoRng>(
+        signing_package: &SigningPackage,
+        signature_shares: &BTreeMap<Identifier, SignatureShare>,
+        pubkeys: &keys::PublicKeyPackage,
+        session_id: &[u8; 32],
+        rng: &mut RNG,
+    ) -> Result<(), Error> {
+        frost::round2::batch_verify_shares_with_session_id(
+            signing_package,
+            signature_shares,
+            pubkeys,
+            session_id,
+            rng,
+        )
+    }
+
+    /// Produce a receipt attesting that the participant contributed to signing
+    /// `message` in the session identified by `session_id`: a Schnorr
+    /// signature under their signing share over a domain tag, `session_id`
+    /// and `H4(message)`.
+    ///
+    /// Returns an error if the key package lacks `Capabilities::SIGN`.
+    pub fn participation_receipt<RNG: RngCore + CryptoRng>(
+        key_package: &keys::KeyPackage,
+        session_id: &[u8],
+        message: &[u8],
+        rng: &mut RNG,
+    ) -> Result<Signature, Error> {
+        frost::round2::participation_receipt(key_package, session_id, message, rng)
+    }
+
+    /// Verify a receipt produced with [`participation_receipt`] by the
+    /// participant with the given verifying share.
+    pub fn verify_participation_receipt(
+        verifying_share: &keys::VerifyingShare,
+        session_id: &[u8],
+        message: &[u8],
+        receipt: &Signature,
+    ) -> Result<(), Error> {
+        frost::round2::verify_participation_receipt(verifying_share, session_id, message, receipt)
+    }
 }
 
 /// A Schnorr signature on FROST(ristretto255, SHA-512).
@@ -389,6 +764,13 @@ pub type Signature = frost_core::Signature<R>;
 /// signature, if the coordinator themselves is a signer and misbehaves, they
 /// can avoid that step. However, at worst, this results in a denial of
 /// service attack due to publishing an invalid signature.
+///
+/// Individual signature shares are only verified if the aggregate signature
+/// fails to verify against the group verifying key, in order to identify the
+/// misbehaving participant (with the `cheater-detection` feature). When all
+/// shares are valid, which is the common case, aggregation costs a single
+/// signature verification, so there is no need to skip share verification
+/// even if shares were already verified upstream.
 pub fn aggregate(
     signing_package: &SigningPackage,
     signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
@@ -397,6 +779,159 @@ pub fn aggregate(
     frost::aggregate(signing_package, signature_shares, pubkeys)
 }
 
+/// Like [`aggregate`], but returns an error if the signing package or the
+/// signature shares contain more than `signer_limit` signers.
+pub fn aggregate_with_signer_limit(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+    signer_limit: usize,
+) -> Result<Signature, Error> {
+    frost::aggregate_with_signer_limit(signing_package, signature_shares, pubkeys, signer_limit)
+}
+
+/// The public intermediate values derived while aggregating a signature.
+pub type AggregateDetails = frost::AggregateDetails<R>;
+
+/// Like [`aggregate`], but also returns the [`AggregateDetails`] derived while
+/// aggregating: the group commitment, the binding factors and the challenge.
+pub fn aggregate_with_details(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<(Signature, AggregateDetails), Error> {
+    frost::aggregate_with_details(signing_package, signature_shares, pubkeys)
+}
+
+/// Like [`aggregate`], for signature shares produced with
+/// [`round2::sign_with_session_id`] for the given `session_id`.
+pub fn aggregate_with_session_id(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+    session_id: &[u8; 32],
+) -> Result<Signature, Error> {
+    frost::aggregate_with_session_id(signing_package, signature_shares, pubkeys, session_id)
+}
+
+/// Like [`aggregate`], but reports the duration of each phase of the
+/// aggregation to `on_metric`; see [`frost::aggregate_instrumented`].
+pub fn aggregate_instrumented(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+    on_metric: impl FnMut(&str, std::time::Duration),
+) -> Result<Signature, Error> {
+    frost::aggregate_instrumented(signing_package, signature_shares, pubkeys, on_metric)
+}
+
+/// Like [`aggregate`], but uses the given `challenge` instead of deriving it,
+/// for signature shares produced with [`round2::sign_with_challenge`].
+///
+/// The caller is responsible for the soundness of the challenge.
+pub fn aggregate_with_challenge(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+    challenge: frost::Scalar<R>,
+) -> Result<Signature, Error> {
+    frost::aggregate_with_challenge(signing_package, signature_shares, pubkeys, challenge)
+}
+
+/// Collects signature shares as they arrive at the coordinator, reporting
+/// progress, and aggregates them once all the signers have sent theirs.
+pub type Aggregator = frost::Aggregator<R>;
+
+/// Recompute the group commitment `R` for a signing operation from the signing
+/// commitments and message in the signing package; the final signature must
+/// contain this `R`.
+pub fn aggregate_commitment(
+    signing_package: &SigningPackage,
+    verifying_key: &VerifyingKey,
+) -> Result<frost::Element<R>, Error> {
+    frost::aggregate_commitment(signing_package, verifying_key)
+}
+
+/// Like [`aggregate_commitment`], for signature shares produced with
+/// [`round2::sign_with_session_id`] for the given `session_id`.
+pub fn aggregate_commitment_with_session_id(
+    signing_package: &SigningPackage,
+    verifying_key: &VerifyingKey,
+    session_id: &[u8; 32],
+) -> Result<frost::Element<R>, Error> {
+    frost::aggregate_commitment_with_session_id(signing_package, verifying_key, session_id)
+}
+
+/// Signing the same message under multiple independent FROST(ristretto255, SHA-512) groups.
+pub mod multi_group {
+    use super::*;
+
+    /// A participant's signing state for several groups, keyed by a
+    /// caller-chosen group identifier `G`.
+    pub type MultiGroupSession<G> = frost::multi_group::MultiGroupSession<R, G>;
+
+    /// Run [`aggregate`](crate::aggregate) for every group, returning one
+    /// [`Signature`] per group.
+    pub fn aggregate_groups<G: Ord + Clone>(
+        signing_packages: &BTreeMap<G, SigningPackage>,
+        signature_shares: &BTreeMap<G, BTreeMap<Identifier, round2::SignatureShare>>,
+        pubkey_packages: &BTreeMap<G, keys::PublicKeyPackage>,
+    ) -> Result<BTreeMap<G, Signature>, Error> {
+        frost::multi_group::aggregate_groups(signing_packages, signature_shares, pubkey_packages)
+    }
+}
+
+/// Threshold FROST(ristretto255, SHA-512) signing of a stream as a chain of signed chunks.
+pub mod chained {
+    use super::*;
+
+    /// The coordinator's state while signing a stream chunk by chunk, where
+    /// each chunk's signature commits to the previous one.
+    pub type ChainedSigningSession = frost::chained::ChainedSigningSession<R>;
+
+    /// Verify the signatures of a stream signed with a
+    /// [`ChainedSigningSession`], given its chunks in order.
+    pub fn verify_chain(
+        chunks: &[&[u8]],
+        signatures: &[Signature],
+        verifying_key: &VerifyingKey,
+    ) -> Result<(), Error> {
+        frost::chained::verify_chain(chunks, signatures, verifying_key)
+    }
+}
+
+/// One-shot FROST(ristretto255, SHA-512) signing when all the key packages are held locally.
+///
+/// Only meant for prototyping, tests and demos, since holding all the signing
+/// shares in a single place defeats the purpose of threshold signatures.
+pub mod simple {
+    use super::*;
+
+    /// Run all the signing rounds locally, with every given key package as a
+    /// signer, and return the aggregated [`Signature`] over `message`.
+    pub fn sign_all<RNG: RngCore + CryptoRng>(
+        message: &[u8],
+        key_packages: &BTreeMap<Identifier, keys::KeyPackage>,
+        pubkeys: &keys::PublicKeyPackage,
+        rng: &mut RNG,
+    ) -> Result<Signature, Error> {
+        frost::simple::sign_all(message, key_packages, pubkeys, rng)
+    }
+
+    /// Like [`sign_all`], but uses the given nonces for each signer, so that
+    /// the signature is deterministic. Only meant for tests: reusing nonces to
+    /// sign different messages leaks the signing shares.
+    #[cfg(feature = "test-vectors")]
+    pub fn sign_all_with_nonces(
+        message: &[u8],
+        key_packages: &BTreeMap<Identifier, keys::KeyPackage>,
+        pubkeys: &keys::PublicKeyPackage,
+        nonces: &BTreeMap<Identifier, round1::SigningNonces>,
+    ) -> Result<Signature, Error> {
+        frost::simple::sign_all_with_nonces(message, key_packages, pubkeys, nonces)
+    }
+}
+
 /// A signing key for a Schnorr signature on FROST(ristretto255, SHA-512).
 pub type SigningKey = frost_core::SigningKey<R>;
 
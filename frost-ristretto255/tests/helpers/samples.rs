@@ -6,11 +6,11 @@ use frost_core::{round1::Nonce, Ciphersuite, Element, Group, Scalar};
 use frost_ristretto255::{
     keys::{
         dkg::{round1, round2},
-        KeyPackage, PublicKeyPackage, SecretShare, SigningShare, VerifiableSecretSharingCommitment,
-        VerifyingShare,
+        KeyPackage, KeySet, PublicKeyPackage, SecretShare, SigningShare,
+        VerifiableSecretSharingCommitment, VerifyingShare,
     },
-    round1::{NonceCommitment, SigningCommitments, SigningNonces},
-    round2::SignatureShare,
+    round1::{NonceCommitment, SignedCommitments, SigningCommitments, SigningNonces},
+    round2::{SignatureShare, SignatureShareWithProof},
     Field, Signature, SigningPackage, VerifyingKey,
 };
 
@@ -52,6 +52,11 @@ pub fn signing_commitments() -> SigningCommitments {
     SigningCommitments::new(hiding_nonce_commitment, binding_nonce_commitment)
 }
 
+/// Generate a sample SignedCommitments.
+pub fn signed_commitments() -> SignedCommitments {
+    SignedCommitments::new(signing_commitments(), signature())
+}
+
 /// Generate a sample SigningPackage.
 pub fn signing_package() -> SigningPackage {
     let identifier = 42u16.try_into().unwrap();
@@ -68,6 +73,32 @@ pub fn signature_share() -> SignatureShare {
     SignatureShare::deserialize(serialized_scalar).unwrap()
 }
 
+/// Generate a sample SignatureShareWithProof.
+pub fn signature_share_with_proof() -> SignatureShareWithProof {
+    let identifier = 42u16.try_into().unwrap();
+    let serialized_element = <C as Ciphersuite>::Group::serialize(&element1());
+    let verifying_key = VerifyingKey::deserialize(serialized_element).unwrap();
+
+    SignatureShare::with_proof(
+        &signing_package(),
+        &verifying_key,
+        identifier,
+        signature_share(),
+    )
+    .unwrap()
+}
+
+/// Generate a sample Signature.
+pub fn signature() -> Signature {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(<C as Ciphersuite>::Group::serialize(&element1()).as_ref());
+    bytes.extend_from_slice(
+        <<C as Ciphersuite>::Group as Group>::Field::serialize(&scalar1()).as_ref(),
+    );
+
+    Signature::deserialize(bytes.try_into().unwrap()).unwrap()
+}
+
 /// Generate a sample SecretShare.
 pub fn secret_share() -> SecretShare {
     let identifier = 42u16.try_into().unwrap();
@@ -105,6 +136,18 @@ pub fn public_key_package() -> PublicKeyPackage {
     PublicKeyPackage::new(verifying_shares, verifying_key)
 }
 
+/// Generate a sample KeySet.
+pub fn keyset() -> KeySet {
+    KeySet::new(
+        public_key_package(),
+        2,
+        3,
+        1_700_000_000,
+        "sample".to_string(),
+    )
+    .unwrap()
+}
+
 /// Generate a sample round1::Package.
 pub fn round1_package() -> round1::Package {
     let serialized_scalar = <<C as Ciphersuite>::Group as Group>::Field::serialize(&scalar1());
@@ -223,6 +223,16 @@ fn check_sign_with_dealer_and_identifiers() {
     >(rng);
 }
 
+#[test]
+fn check_sign_with_sparse_identifiers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_sparse_identifiers::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
 #[test]
 fn check_sign_with_missing_identifier() {
     let rng = thread_rng();
@@ -240,3 +250,518 @@ fn check_sign_with_incorrect_commitments() {
         _,
     >(rng);
 }
+
+#[test]
+fn check_split_deterministic() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_deterministic::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_commitment_pool() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_commitment_pool::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_public_key_package_audit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_audit::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signature_share_with_proof() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_share_with_proof::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_commit_with_aux() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_commit_with_aux::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_collapse_to_single() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_collapse_to_single::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_to_group_commitment_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_to_group_commitment_share::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_check_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_check_share::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_key_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_packages::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_z_is_sum_of_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_z_is_sum_of_shares::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_message_digest() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_message_digest::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_aggregate_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_commitment::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signer_limit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signer_limit::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_backend() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_backend::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_proof_of_possession() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_proof_of_possession::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_verify_rejects_degenerate_signatures() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_rejects_degenerate_signatures::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_multi_group_session() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_multi_group_session::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_split_with_custom_identifier_list() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_with_custom_identifier_list::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signing_package_equality_ignores_order() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_equality_ignores_order::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_identifier_try_from_bytes() {
+    frost_core::tests::ciphersuite_generic::check_identifier_try_from_bytes::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_public_key_package_signer_counts() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_signer_counts::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_commitments::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_reconstructed_key() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_reconstructed_key::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_minimal_quorums() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_minimal_quorums::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_missing_signers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_missing_signers::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_identifier_ordering() {
+    frost_core::tests::ciphersuite_generic::check_identifier_ordering::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_dkg_with_serialized_secret_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_dkg_with_serialized_secret_packages::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_capabilities() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_capabilities::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_preprocessed() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_preprocessed::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_signing_share_entropy() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_share_entropy::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_aggregate_with_details() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_details::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_single_rng_ceremony() {
+    frost_core::tests::ciphersuite_generic::check_single_rng_ceremony::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_test_dealer() {
+    frost_core::tests::ciphersuite_generic::check_test_dealer::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_aggregate_rejects_unexpected_signer() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_rejects_unexpected_signer::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregator() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregator::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_simple_sign_all() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_simple_sign_all::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_key_package_check_group() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_check_group::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_all_with_fixed_nonces() {
+    frost_core::tests::ciphersuite_generic::check_sign_all_with_fixed_nonces::<Ristretto255Sha512>(
+    );
+}
+
+#[test]
+fn check_chained_signing() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_chained_signing::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_error_codes() {
+    frost_core::tests::ciphersuite_generic::check_error_codes::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_split_with_polynomial() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_with_polynomial::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_conformance() {
+    let mut rng = thread_rng();
+
+    frost_core::conformance::run::<Ristretto255Sha512, _>(&mut rng);
+}
+
+#[test]
+fn check_sign_bound_to_set() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_bound_to_set::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_signature_len() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_len::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_message_limit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_message_limit::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_commit_from_seed() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_commit_from_seed::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_batch_verify_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_batch_verify_shares::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_signature_framing() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_framing::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_degenerate_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_degenerate_commitment::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_group_key_from_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_key_from_packages::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_with_challenge() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_challenge::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_verification_residual() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verification_residual::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_participation_receipt() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_participation_receipt::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_abort_nonces() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_abort_nonces::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_signed_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signed_commitments::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_colliding_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_colliding_commitments::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_with_session_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_session_id::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_vss_commitment_threshold() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_vss_commitment_threshold::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_verify_pair() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_pair::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_evaluate_at() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_evaluate_at::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_instrumented() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_instrumented::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_restrict_public_key_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_restrict_public_key_package::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signing_key_matches() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_key_matches::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_signature_share_with_identifier() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_share_with_identifier::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregate_insufficient_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_insufficient_shares::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
@@ -0,0 +1,45 @@
+mod helpers;
+
+use frost_ristretto255::Signature;
+use helpers::samples;
+use insta::assert_snapshot;
+
+fn check_parseable(json: &str) {
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert!(value.is_object());
+}
+
+#[test]
+fn check_signing_commitments_debug_json() {
+    let json = samples::signing_commitments().to_debug_json();
+    check_parseable(&json);
+    assert_snapshot!(json);
+}
+
+#[test]
+fn check_signing_package_debug_json() {
+    let json = samples::signing_package().to_debug_json();
+    check_parseable(&json);
+    assert_snapshot!(json);
+}
+
+#[test]
+fn check_signature_share_debug_json() {
+    let json = samples::signature_share().to_debug_json();
+    check_parseable(&json);
+    assert_snapshot!(json);
+}
+
+#[test]
+fn check_signature_debug_json() {
+    let signature = samples::signature();
+    let json = signature.to_debug_json();
+    check_parseable(&json);
+    assert_snapshot!(json);
+    assert_eq!(
+        json,
+        Signature::deserialize(signature.serialize())
+            .unwrap()
+            .to_debug_json()
+    );
+}
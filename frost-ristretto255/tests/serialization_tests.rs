@@ -5,11 +5,11 @@ mod helpers;
 use frost_ristretto255::{
     keys::{
         dkg::{round1, round2},
-        KeyPackage, PublicKeyPackage, SecretShare,
+        Capabilities, KeyPackage, KeySet, PublicKeyComponent, PublicKeyPackage, SecretShare,
     },
-    round1::{SigningCommitments, SigningNonces},
-    round2::SignatureShare,
-    SigningPackage,
+    round1::{SignedCommitments, SigningCommitments, SigningNonces},
+    round2::{SignatureShare, SignatureShareWithProof},
+    Error, Ristretto255Sha512, SigningPackage,
 };
 
 use helpers::samples;
@@ -34,6 +34,17 @@ fn check_signing_commitments_postcard_serialization() {
     );
 }
 
+#[test]
+fn check_signed_commitments_postcard_serialization() {
+    let signed_commitments = samples::signed_commitments();
+    let bytes: Vec<_> = signed_commitments.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        signed_commitments,
+        SignedCommitments::deserialize(&bytes).unwrap()
+    );
+}
+
 #[test]
 fn check_signing_package_postcard_serialization() {
     let signing_package = samples::signing_package();
@@ -52,6 +63,18 @@ fn check_signature_share_postcard_serialization() {
     assert_snapshot!(hex::encode(bytes));
     assert_eq!(signature_share, SignatureShare::deserialize(bytes).unwrap());
 }
+
+#[test]
+fn check_signature_share_with_proof_postcard_serialization() {
+    let signature_share_with_proof = samples::signature_share_with_proof();
+    let bytes: Vec<_> = signature_share_with_proof.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        signature_share_with_proof,
+        SignatureShareWithProof::deserialize(&bytes).unwrap()
+    );
+}
+
 #[test]
 fn check_secret_share_postcard_serialization() {
     let secret_share = samples::secret_share();
@@ -68,6 +91,44 @@ fn check_key_package_postcard_serialization() {
     assert_eq!(key_package, KeyPackage::deserialize(&bytes).unwrap());
 }
 
+#[test]
+fn check_key_package_v0_postcard_deserialization() {
+    // The snapshot of the sample key package is unchanged since before
+    // capabilities were added, i.e. it uses format version 0.
+    let snapshot = include_str!(
+        "snapshots/serialization_tests__check_key_package_postcard_serialization.snap"
+    );
+    let bytes = hex::decode(snapshot.lines().last().unwrap()).unwrap();
+    let key_package = KeyPackage::deserialize(&bytes).unwrap();
+    assert_eq!(key_package, samples::key_package());
+    assert_eq!(*key_package.capabilities(), Capabilities::ALL);
+
+    // Key packages with restricted capabilities use format version 1.
+    let key_package = samples::key_package().with_capabilities(Capabilities::REPAIR);
+    let bytes: Vec<_> = key_package.serialize().unwrap();
+    assert_eq!(bytes[0], 1);
+    assert_eq!(key_package, KeyPackage::deserialize(&bytes).unwrap());
+}
+
+#[test]
+fn check_key_package_public_part_postcard_serialization() {
+    let key_package = samples::key_package();
+    let public_part = key_package.public_part();
+    let bytes: Vec<_> = public_part.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        public_part,
+        PublicKeyComponent::deserialize(&bytes).unwrap()
+    );
+
+    // The signing share must not leak into the serialized public part.
+    let signing_share = key_package.signing_share().serialize();
+    let signing_share: &[u8] = signing_share.as_ref();
+    assert!(!bytes
+        .windows(signing_share.len())
+        .any(|window| window == signing_share));
+}
+
 #[test]
 fn check_public_key_package_postcard_serialization() {
     let public_key_package = samples::public_key_package();
@@ -79,6 +140,58 @@ fn check_public_key_package_postcard_serialization() {
     );
 }
 
+#[test]
+fn check_public_key_package_v0_postcard_deserialization() {
+    // The snapshot of the sample package is unchanged since before
+    // `min_signers` was recorded, i.e. it uses format version 0.
+    let snapshot = include_str!(
+        "snapshots/serialization_tests__check_public_key_package_postcard_serialization.snap"
+    );
+    let bytes = hex::decode(snapshot.lines().last().unwrap()).unwrap();
+    let public_key_package = PublicKeyPackage::deserialize(&bytes).unwrap();
+    assert_eq!(public_key_package, samples::public_key_package());
+    assert_eq!(public_key_package.min_signers(), None);
+
+    // Packages recording `min_signers` use format version 1.
+    let (_, public_key_package) =
+        frost_core::keys::test_dealer::<Ristretto255Sha512>(3, 2).unwrap();
+    let bytes: Vec<_> = public_key_package.serialize().unwrap();
+    assert_eq!(bytes[0], 1);
+    let decoded = PublicKeyPackage::deserialize(&bytes).unwrap();
+    assert_eq!(decoded, public_key_package);
+    assert_eq!(decoded.min_signers(), Some(2));
+}
+
+#[test]
+fn check_keyset_postcard_serialization() {
+    let keyset = samples::keyset();
+    let bytes: Vec<_> = keyset.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(keyset, KeySet::deserialize(&bytes).unwrap());
+}
+
+#[test]
+fn check_keyset_corrupted_header() {
+    let mut bytes: Vec<_> = samples::keyset().serialize().unwrap();
+    bytes[0] ^= 1;
+    assert_eq!(
+        KeySet::deserialize(&bytes).unwrap_err(),
+        Error::DeserializationError
+    );
+    assert_eq!(
+        KeySet::deserialize(&bytes[..4]).unwrap_err(),
+        Error::DeserializationError
+    );
+
+    // The wrapped header is still checked.
+    let mut bytes: Vec<_> = samples::keyset().serialize().unwrap();
+    bytes[frost_ristretto255::keys::KEYSET_MAGIC.len()] = 2;
+    assert_eq!(
+        KeySet::deserialize(&bytes).unwrap_err(),
+        Error::UnsupportedVersion
+    );
+}
+
 #[test]
 fn check_round1_package_postcard_serialization() {
     let round1_package = samples::round1_package();
@@ -100,3 +213,77 @@ fn check_round2_package_postcard_serialization() {
         round2::Package::deserialize(&bytes).unwrap()
     );
 }
+
+#[test]
+fn check_ciphersuite_mismatch_postcard_deserialization() {
+    // Replace the ciphersuite ID in the header (the 4 bytes after the version)
+    // to simulate a package serialized by a different ciphersuite.
+    let mut bytes: Vec<_> = samples::key_package().serialize().unwrap();
+    bytes[1..5].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(
+        KeyPackage::deserialize(&bytes).unwrap_err(),
+        Error::CiphersuiteMismatch
+    );
+
+    let mut bytes: Vec<_> = samples::public_key_package().serialize().unwrap();
+    bytes[1..5].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(
+        PublicKeyPackage::deserialize(&bytes).unwrap_err(),
+        Error::CiphersuiteMismatch
+    );
+
+    // Other malformed inputs still report a generic error, even if their
+    // header has a different ciphersuite or an unknown version.
+    let bytes: Vec<_> = samples::key_package().serialize().unwrap();
+    assert_eq!(
+        KeyPackage::deserialize(&bytes[..bytes.len() - 1]).unwrap_err(),
+        Error::DeserializationError
+    );
+    let mut truncated = bytes[..bytes.len() - 1].to_vec();
+    truncated[1..5].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(
+        KeyPackage::deserialize(&truncated).unwrap_err(),
+        Error::DeserializationError
+    );
+    truncated[0] = 0xff;
+    assert_eq!(
+        KeyPackage::deserialize(&truncated).unwrap_err(),
+        Error::DeserializationError
+    );
+}
+
+#[test]
+fn check_unsupported_version_postcard_deserialization() {
+    // The current version round-trips.
+    let key_package = samples::key_package();
+    let mut bytes: Vec<_> = key_package.serialize().unwrap();
+    assert_eq!(key_package, KeyPackage::deserialize(&bytes).unwrap());
+
+    // A blob with a version newer than the latest one (the first header
+    // byte) is rejected.
+    bytes[0] = 2;
+    assert_eq!(
+        KeyPackage::deserialize(&bytes).unwrap_err(),
+        Error::UnsupportedVersion
+    );
+
+    let secret_share = samples::secret_share();
+    let mut bytes: Vec<_> = secret_share.serialize().unwrap();
+    assert_eq!(secret_share, SecretShare::deserialize(&bytes).unwrap());
+
+    bytes[0] = 2;
+    assert_eq!(
+        SecretShare::deserialize(&bytes).unwrap_err(),
+        Error::UnsupportedVersion
+    );
+}
+
+#[test]
+fn check_test_dealer_is_stable() {
+    let (shares, pubkeys) = frost_core::keys::test_dealer::<Ristretto255Sha512>(3, 2).unwrap();
+    let mut bytes = pubkeys.serialize().unwrap();
+    for share in shares.values() {
+        bytes.extend(share.serialize().unwrap());
+    }
+    assert_snapshot!(hex::encode(&bytes));
+}
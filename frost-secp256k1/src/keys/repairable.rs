@@ -12,7 +12,7 @@ use std::collections::BTreeMap;
 use crate::{frost, Ciphersuite, CryptoRng, Identifier, RngCore, Scalar};
 use crate::{Error, Secp256K1Sha256};
 
-use super::{SecretShare, VerifiableSecretSharingCommitment};
+use super::{PublicKeyPackage, SecretShare, VerifiableSecretSharingCommitment};
 
 /// Step 1 of RTS.
 ///
@@ -30,6 +30,19 @@ pub fn repair_share_step_1<C: Ciphersuite, R: RngCore + CryptoRng>(
     frost::keys::repairable::repair_share_step_1(helpers, share_i, rng, participant)
 }
 
+/// Commit to the "delta" values generated by `helper_i` in step 1.
+///
+/// The commitments are sent to the `participant` (and can be sent to the other
+/// helpers) so that [`repair_share_robust`] can identify a helper that
+/// misbehaved.
+///
+/// Returns a BTreeMap mapping each helper to the commitment to the delta sent to them.
+pub fn repair_share_delta_commitments(
+    deltas_i: &BTreeMap<Identifier, Scalar>,
+) -> BTreeMap<Identifier, frost::Element<Secp256K1Sha256>> {
+    frost::keys::repairable::repair_share_delta_commitments(deltas_i)
+}
+
 /// Step 2 of RTS.
 ///
 /// Generates the `sigma` values from all `deltas` received from `helpers`
@@ -54,6 +67,35 @@ pub fn repair_share_step_3(
     frost::keys::repairable::repair_share_step_3(sigmas, identifier, commitment)
 }
 
+/// Step 3 of RTS, checking the contributions of the helpers.
+///
+/// Like [`repair_share_step_3`], but `sigmas` maps each helper to the `sigma_j`
+/// they sent, and `delta_commitments` maps each helper to the commitments to
+/// their deltas returned by [`repair_share_delta_commitments`]. Each helper's
+/// delta commitments are checked against their verifying share in `pubkeys`,
+/// and each `sigma_j` against the commitments to the deltas it sums.
+///
+/// Returns the repaired `SecretShare`, or [`Error::InvalidParticipantSecretShare`] naming
+/// the first helper found to have sent a wrong contribution. Note that a
+/// helper which sends another helper a delta that does not match its
+/// commitment will cause the latter to be named; helpers can avoid this by
+/// checking the deltas they receive against the commitments.
+pub fn repair_share_robust(
+    sigmas: &BTreeMap<Identifier, Scalar>,
+    delta_commitments: &BTreeMap<Identifier, BTreeMap<Identifier, frost::Element<Secp256K1Sha256>>>,
+    pubkeys: &PublicKeyPackage,
+    identifier: Identifier,
+    commitment: &VerifiableSecretSharingCommitment,
+) -> Result<SecretShare, Error> {
+    frost::keys::repairable::repair_share_robust(
+        sigmas,
+        delta_commitments,
+        pubkeys,
+        identifier,
+        commitment,
+    )
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -98,4 +140,10 @@ mod tests {
             _,
         >(rng);
     }
+
+    #[test]
+    fn check_repair_share_robust() {
+        let rng = thread_rng();
+        frost_core::tests::repairable::check_repair_share_robust::<Secp256K1Sha256, _>(rng);
+    }
 }
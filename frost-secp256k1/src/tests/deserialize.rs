@@ -35,3 +35,8 @@ fn check_deserialize_identity() {
     let r = <Secp256K1Sha256 as Ciphersuite>::Group::deserialize(&encoded_identity);
     assert_eq!(r, Err(GroupError::MalformedElement));
 }
+
+#[test]
+fn check_deserialize_scalar_bounds() {
+    frost_core::tests::ciphersuite_generic::check_deserialize_scalar_bounds::<Secp256K1Sha256>();
+}
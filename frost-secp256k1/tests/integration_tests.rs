@@ -223,6 +223,15 @@ fn check_sign_with_dealer_and_identifiers() {
     >(rng);
 }
 
+#[test]
+fn check_sign_with_sparse_identifiers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_sparse_identifiers::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
 #[test]
 fn check_sign_with_missing_identifier() {
     let rng = thread_rng();
@@ -239,3 +248,491 @@ fn check_sign_with_incorrect_commitments() {
         _,
     >(rng);
 }
+
+#[test]
+fn check_split_deterministic() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_deterministic::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_commitment_pool() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_commitment_pool::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_public_key_package_audit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_audit::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signature_share_with_proof() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_share_with_proof::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_commit_with_aux() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_commit_with_aux::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_collapse_to_single() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_collapse_to_single::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_to_group_commitment_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_to_group_commitment_share::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_check_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_check_share::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_key_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_packages::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_z_is_sum_of_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_z_is_sum_of_shares::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_with_message_digest() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_message_digest::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_aggregate_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_commitment::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signer_limit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signer_limit::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_backend() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_backend::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_proof_of_possession() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_proof_of_possession::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_verify_rejects_degenerate_signatures() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_rejects_degenerate_signatures::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_multi_group_session() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_multi_group_session::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_split_with_custom_identifier_list() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_with_custom_identifier_list::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signing_package_equality_ignores_order() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_equality_ignores_order::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_identifier_try_from_bytes() {
+    frost_core::tests::ciphersuite_generic::check_identifier_try_from_bytes::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_public_key_package_signer_counts() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_signer_counts::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_commitments::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_reconstructed_key() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_reconstructed_key::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_minimal_quorums() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_minimal_quorums::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_missing_signers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_missing_signers::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_identifier_ordering() {
+    frost_core::tests::ciphersuite_generic::check_identifier_ordering::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_dkg_with_serialized_secret_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_dkg_with_serialized_secret_packages::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_capabilities() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_capabilities::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_preprocessed() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_preprocessed::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signing_share_entropy() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_share_entropy::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_with_details() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_details::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_single_rng_ceremony() {
+    frost_core::tests::ciphersuite_generic::check_single_rng_ceremony::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_test_dealer() {
+    frost_core::tests::ciphersuite_generic::check_test_dealer::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_aggregate_rejects_unexpected_signer() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_rejects_unexpected_signer::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregator() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregator::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_simple_sign_all() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_simple_sign_all::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_key_package_check_group() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_check_group::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_all_with_fixed_nonces() {
+    frost_core::tests::ciphersuite_generic::check_sign_all_with_fixed_nonces::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_chained_signing() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_chained_signing::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_error_codes() {
+    frost_core::tests::ciphersuite_generic::check_error_codes::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_split_with_polynomial() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_split_with_polynomial::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_conformance() {
+    let mut rng = thread_rng();
+
+    frost_core::conformance::run::<Secp256K1Sha256, _>(&mut rng);
+}
+
+#[test]
+fn check_sign_bound_to_set() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_bound_to_set::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signature_len() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_len::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_message_limit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_message_limit::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_commit_from_seed() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_commit_from_seed::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_batch_verify_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_batch_verify_shares::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signature_framing() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_framing::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_degenerate_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_degenerate_commitment::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_group_key_from_packages() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_key_from_packages::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_with_challenge() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_challenge::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_verification_residual() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verification_residual::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_participation_receipt() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_participation_receipt::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_abort_nonces() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_abort_nonces::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signed_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signed_commitments::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_colliding_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_colliding_commitments::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_session_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_session_id::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_vss_commitment_threshold() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_vss_commitment_threshold::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_verify_pair() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_pair::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_evaluate_at() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_evaluate_at::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_instrumented() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_instrumented::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_restrict_public_key_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_restrict_public_key_package::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signing_key_matches() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_key_matches::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signature_share_with_identifier() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_share_with_identifier::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregate_insufficient_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_insufficient_shares::<Secp256K1Sha256, _>(
+        rng,
+    );
+}